@@ -210,18 +210,15 @@ def calculate_separation(
     params: Params,
     query: xx.Query[tuple[xx.Transform2, Separation]],
 ) -> None:
-    (_, separation) = query.result()
+    (transform, separation) = query.result()
     separation.displacement_sum.fill(0)
 
-    boid1, boid2 = query.product_2()
+    boid1, boid2 = query.pairs_within(
+        transform.translation, params.separation_radius
+    )
     transform1, separation = boid1
     transform2, _ = boid2
     displacement = transform1.translation - transform2.translation
-    distance = np.linalg.norm(displacement, axis=0)
-    needs_separation = distance < params.separation_radius
-
-    displacement = displacement[:, needs_separation]
-    separation = separation[needs_separation]
     separation.displacement_sum += displacement
 
 
@@ -229,18 +226,20 @@ def calculate_alignment(
     params: Params,
     query: xx.Query[tuple[xx.Transform2, Velocity, Alignment]],
 ) -> None:
-    (_, _, alignment) = query.result()
+    (transform, _, alignment) = query.result()
     alignment.velocity_sum.fill(0)
     alignment.num_neighbors.fill(0)
 
-    boid1, boid2 = query.product_2()
+    boid1, boid2 = query.pairs_within(
+        transform.translation, params.visible_radius
+    )
     transform1, velocity1, alignment = boid1
     transform2, velocity2, _ = boid2
 
+    # Boids close enough to separate from are left out of alignment.
     displacement = transform1.translation - transform2.translation
     distance = np.linalg.norm(displacement, axis=0)
     needs_alignment = distance > params.separation_radius
-    needs_alignment &= distance < params.visible_radius
 
     velocity1 = velocity1[needs_alignment]
     alignment = alignment[needs_alignment]
@@ -254,18 +253,20 @@ def calculate_cohesion(
     params: Params,
     query: xx.Query[tuple[xx.Transform2, Cohesion]],
 ) -> None:
-    (_, cohesion) = query.result()
+    (transform, cohesion) = query.result()
     cohesion.translation_sum.fill(0)
     cohesion.num_neighbors.fill(0)
 
-    boid1, boid2 = query.product_2()
+    boid1, boid2 = query.pairs_within(
+        transform.translation, params.visible_radius
+    )
     transform1, cohesion = boid1
     transform2, _ = boid2
 
+    # Boids close enough to separate from are left out of cohesion.
     displacement = transform1.translation - transform2.translation
     distance = np.linalg.norm(displacement, axis=0)
     needs_cohesion = distance > params.separation_radius
-    needs_cohesion &= distance < params.visible_radius
 
     transform1 = transform1[needs_cohesion]
     cohesion = cohesion[needs_cohesion]
@@ -8,16 +8,28 @@ from xecs._internal.component import Component, ComponentPool
 from xecs._internal.entity_id import EntityId
 from xecs._internal.events import EventReader, EventWriter
 from xecs._internal.float32 import float32
+from xecs._internal.float64 import float64
 from xecs._internal.float_ import Float, float_
 from xecs._internal.input import Keyboard, KeyboardButton, Mouse, MouseButton
+from xecs._internal.int8 import int8
+from xecs._internal.int16 import int16
 from xecs._internal.int32 import int32
+from xecs._internal.int64 import int64
 from xecs._internal.int_ import Int, int_
 from xecs._internal.py_field import PyField, py_field
-from xecs._internal.query import Query
+from xecs._internal.query import (
+    Added,
+    Changed,
+    Maybe,
+    Query,
+    With,
+    Without,
+)
 from xecs._internal.real_time_app import (
     RealTimeApp,
     RealTimeAppPlugin,
 )
+from xecs._internal.removed_components import RemovedComponents
 from xecs._internal.resource import Resource
 from xecs._internal.simulation_app import SimulationApp
 from xecs._internal.struct import Struct
@@ -31,14 +43,36 @@ from xecs._internal.systems import (
     SystemSpec,
 )
 from xecs._internal.transform2 import Transform2
+from xecs._internal.uint8 import uint8
+from xecs._internal.uint16 import uint16
+from xecs._internal.uint32 import uint32
+from xecs._internal.uint64 import uint64
 from xecs._internal.vec2 import Vec2
 from xecs._internal.world import World
-from xecs.xecs import ArrayViewIndices, Bool, Duration, Float32, Int32
+from xecs.xecs import (
+    ArrayViewIndices,
+    Bool,
+    Duration,
+    Float32,
+    Float64,
+    Int8,
+    Int16,
+    Int32,
+    Int64,
+    KdTree,
+    SpatialGrid,
+    UInt8,
+    UInt16,
+    UInt32,
+    UInt64,
+)
 
 __all__ = [
+    "Added",
     "ArrayViewIndices",
     "bool_",
     "Bool",
+    "Changed",
     "Commands",
     "Component",
     "ComponentPool",
@@ -52,12 +86,22 @@ __all__ = [
     "Float",
     "float32",
     "Float32",
+    "float64",
+    "Float64",
     "int_",
     "Int",
+    "int8",
+    "Int8",
+    "int16",
+    "Int16",
     "int32",
     "Int32",
+    "int64",
+    "Int64",
+    "KdTree",
     "Keyboard",
     "KeyboardButton",
+    "Maybe",
     "Mouse",
     "MouseButton",
     "PendingStartupSystems",
@@ -67,13 +111,25 @@ __all__ = [
     "Query",
     "RealTimeApp",
     "RealTimeAppPlugin",
+    "RemovedComponents",
     "Resource",
     "SimulationApp",
+    "SpatialGrid",
     "StartupSystems",
     "Struct",
     "Systems",
     "SystemSpec",
     "Transform2",
+    "uint8",
+    "UInt8",
+    "uint16",
+    "UInt16",
+    "uint32",
+    "UInt32",
+    "uint64",
+    "UInt64",
     "Vec2",
+    "With",
+    "Without",
     "World",
 ]
@@ -1,10 +1,17 @@
+import typing
 from collections.abc import Iterable
 
+import numpy as np
+import numpy.typing as npt
+
 from xecs._internal.component import Component
 from xecs._internal.entity_id import EntityId
 from xecs._internal.world import World
 from xecs.xecs import ArrayViewIndices, RustApp
 
+if typing.TYPE_CHECKING:
+    from xecs.xecs import ComponentId
+
 
 class Commands:
     """
@@ -37,29 +44,126 @@ class Commands:
         Returns:
             For each component type in `components`, the indices
             of the new components in each component pool.
+        Raises:
+            ValueError: If a component appears more than once in
+                `components`.
         See Also:
             * :meth:`.World.get_view`: The return indices can
               be used with this method to access the newly spawned
               entities.
         """
-        indices = []
+        components = tuple(components)
         component_ids = [Component.component_ids[EntityId]]
-        entity_id_indices = None
         for component in components:
-            component_id = Component.component_ids[component]
-            pool = self._world.p_get_pool(component)
-            indices.append(pool.p_spawn(num))
-            if component is EntityId:
-                entity_id_indices = indices[-1]
-            else:
-                component_ids.append(component_id)
+            if component is not EntityId:
+                component_ids.append(Component.component_ids[component])
 
-        entity_id_pool = self._world.p_get_pool(EntityId)
-        if entity_id_indices is None:
-            entity_id_indices = entity_id_pool.p_spawn(num)
-
-        entity_ids = self._app.spawn(component_ids, num)
+        entity_ids, spawned_indices = self._app.spawn(component_ids, num)
+        entity_id_indices, *other_indices = spawned_indices
         self._world.get_view(EntityId, entity_id_indices).value.fill(
             entity_ids
         )
-        return indices
+        other_indices_iter = iter(other_indices)
+        return [
+            entity_id_indices
+            if component is EntityId
+            else next(other_indices_iter)
+            for component in components
+        ]
+
+    def despawn(self, entity_ids: npt.NDArray[np.uint32]) -> None:
+        """
+        Remove entities from the :class:`~xecs.World`.
+
+        The components of the entities are removed from every component
+        pool and their slots are reused by entities spawned later.
+
+        Parameters:
+            entity_ids: The ids of the entities to remove.
+        Raises:
+            KeyError: If any of the entities is not alive.
+        Note:
+            Components of entities spawned into reused slots hold the
+            values of the despawned entities until they are filled.
+        """
+        self._app.despawn(entity_ids)
+
+    def insert_components(
+        self,
+        entity_ids: npt.NDArray[np.uint32],
+        components: Iterable[type[Component]],
+    ) -> list[ArrayViewIndices]:
+        """
+        Add components to existing entities.
+
+        Entities which already hold a component keep their existing
+        component, which is included in the returned indices.
+
+        Parameters:
+            entity_ids: The ids of the entities.
+            components: The components to add.
+        Returns:
+            For each component type in `components`, the indices
+            of the components of each entity in each component pool.
+        Raises:
+            KeyError: If any of the entities is not alive.
+            ValueError: If a component appears more than once in
+                `components`.
+        See Also:
+            * :meth:`.World.get_view`: The return indices can
+              be used with this method to access the newly added
+              components.
+        """
+        return self._app.insert_components(
+            entity_ids, self._component_ids(components)
+        )
+
+    def remove_components(
+        self,
+        entity_ids: npt.NDArray[np.uint32],
+        components: Iterable[type[Component]],
+    ) -> None:
+        """
+        Remove components from existing entities.
+
+        Entities which do not hold a component are left unchanged.
+
+        Parameters:
+            entity_ids: The ids of the entities.
+            components: The components to remove.
+        Raises:
+            KeyError: If any of the entities is not alive.
+        """
+        self._app.remove_components(
+            entity_ids, self._component_ids(components)
+        )
+
+    @staticmethod
+    def _component_ids(
+        components: Iterable[type[Component]],
+    ) -> list["ComponentId"]:
+        component_ids = []
+        for component in components:
+            if component is EntityId:
+                raise ValueError(
+                    "EntityId is added and removed with spawn and despawn"
+                )
+            component_ids.append(Component.component_ids[component])
+        return component_ids
+
+    def is_alive(
+        self,
+        entity_ids: npt.NDArray[np.uint32],
+    ) -> npt.NDArray[np.bool_]:
+        """
+        Check if entities are alive.
+
+        Entity ids of despawned entities are never alive, even if
+        their slot is reused by a newly spawned entity.
+
+        Parameters:
+            entity_ids: The ids of the entities to check.
+        Returns:
+            For each entity, whether it is alive.
+        """
+        return self._app.is_alive(entity_ids)
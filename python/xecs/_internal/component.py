@@ -1,15 +1,13 @@
 import inspect
 import typing
 
-import numpy as np
-import numpy.typing as npt
-
+from xecs._internal.field_options import p_column_from_indices
 from xecs._internal.py_field import PyField, PyFieldError
 from xecs._internal.struct import Struct
-from xecs.xecs import ArrayViewIndices
+from xecs.xecs import ArrayViewIndices, Column
 
 if typing.TYPE_CHECKING:
-    from xecs.xecs import ComponentId
+    from xecs.xecs import ComponentId, GetItemKey
 
 ComponentT = typing.TypeVar("ComponentT", bound="Component")
 
@@ -23,22 +21,44 @@ class ComponentPool(typing.Generic[ComponentT]):
     A preallocated pool of components.
     """
 
-    __slots__ = "p_component", "p_capacity"
+    __slots__ = (
+        "p_component",
+        "p_capacity",
+        "p_growth_factor",
+        "p_max_capacity",
+        "p_in_app",
+    )
 
     p_component: ComponentT
     p_capacity: int
+    p_growth_factor: float | None
+    p_max_capacity: int | None
+    p_in_app: bool
 
     @staticmethod
     def p_new(
         component: ComponentT,
         capacity: int,
+        growth_factor: float | None,
+        max_capacity: int | None,
     ) -> "ComponentPool[ComponentT]":
         component_pool: ComponentPool[ComponentT] = ComponentPool()
         component_pool.p_component = component
         component_pool.p_capacity = capacity
+        component_pool.p_growth_factor = growth_factor
+        component_pool.p_max_capacity = max_capacity
+        component_pool.p_in_app = False
         return component_pool
 
     def p_spawn(self, num: int) -> ArrayViewIndices:
+        # Only for pools which are not part of an app. Once a pool is
+        # added to an app, spawn through Commands so that the app can
+        # keep track of the entities.
+        if self.p_in_app:
+            raise RuntimeError(
+                "cannot spawn directly into a pool which belongs to an app, "
+                "use Commands.spawn instead"
+            )
         return self.p_component.p_indices.spawn(num)
 
 
@@ -55,15 +75,37 @@ class Component:
     p_indices: ArrayViewIndices
 
     @classmethod
-    def create_pool(cls, capacity: int) -> ComponentPool[typing.Self]:
+    def create_pool(
+        cls,
+        capacity: int,
+        *,
+        growable: bool = False,
+        growth_factor: float = 2.0,
+        max_capacity: int | None = None,
+    ) -> ComponentPool[typing.Self]:
         """
         Create a preallocated pool of components.
 
+        By default the pool has a fixed size. A growable pool instead
+        reallocates every field of the component when it is full. Views
+        taken before the pool grows remain valid.
+
         Parameters:
-            capacity: The maximum number of components the pool can hold.
+            capacity: The number of components the pool can hold before
+                it is full.
+            growable: Whether the pool grows when it is full, rather
+                than raising an error when spawning.
+            growth_factor: What the capacity of a growable pool is
+                multiplied by when it grows.
+            max_capacity: The capacity a growable pool cannot grow
+                beyond. If ``None``, the pool can grow without limit.
         Returns:
             The component pool.
         """
+        if growth_factor < 1:
+            raise ValueError("growth_factor must be at least 1")
+        if max_capacity is not None and max_capacity < capacity:
+            raise ValueError("max_capacity must be at least capacity")
         component = cls()
         component.p_indices = ArrayViewIndices.with_capacity(capacity)
         for key, value in inspect.get_annotations(cls).items():
@@ -92,15 +134,21 @@ class Component:
                 setattr(
                     component,
                     key,
-                    value.p_from_indices(
+                    p_column_from_indices(
+                        value,
                         component.p_indices,
                         getattr(cls, key, value.p_default_value()),
                     ),
                 )
 
-        return ComponentPool.p_new(component, capacity)
+        return ComponentPool.p_new(
+            component,
+            capacity,
+            growth_factor if growable else None,
+            max_capacity,
+        )
 
-    def __getitem__(self, key: npt.NDArray[np.bool_]) -> typing.Self:
+    def __getitem__(self, key: "GetItemKey") -> typing.Self:
         cls = self.__class__
         component = cls()
         component.p_indices = self.p_indices[key]
@@ -113,6 +161,16 @@ class Component:
             )
         return component
 
+    def p_columns(self) -> list[Column]:
+        columns = []
+        for attr_name in inspect.get_annotations(type(self)):
+            attr_value = getattr(self, attr_name)
+            if isinstance(attr_value, Struct):
+                columns.extend(attr_value.p_columns())
+            else:
+                columns.append(attr_value.p_column())
+        return columns
+
     def p_new_view_with_indices(
         self,
         indices: ArrayViewIndices,
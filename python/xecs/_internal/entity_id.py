@@ -8,4 +8,11 @@ class EntityId(Component):
     """
 
     value: UInt32
-    """The entity id."""
+    """
+    The entity id.
+
+    The lower 24 bits hold the index of the entity and the upper 8 bits
+    hold its generation. Indices of despawned entities are reused, but
+    with a new generation, so stored ids of despawned entities are
+    never mistaken for the entities which replace them.
+    """
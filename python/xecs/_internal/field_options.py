@@ -0,0 +1,37 @@
+import typing
+
+from xecs.xecs import ArrayViewIndices
+
+if typing.TYPE_CHECKING:
+    from xecs.xecs import Overflow
+
+
+class FieldOptions:
+    """
+    The default value of an integer field, along with what arithmetic on
+    it does when a result does not fit.
+    """
+
+    __slots__ = "p_default", "p_overflow"
+
+    p_default: int
+    p_overflow: "Overflow"
+
+    @staticmethod
+    def p_new(default: int, overflow: "Overflow") -> "FieldOptions":
+        options = FieldOptions()
+        options.p_default = default
+        options.p_overflow = overflow
+        return options
+
+
+def p_column_from_indices(
+    column_type: typing.Any,
+    indices: ArrayViewIndices,
+    default: typing.Any,
+) -> typing.Any:
+    if isinstance(default, FieldOptions):
+        return column_type.p_from_indices(
+            indices, default.p_default, default.p_overflow
+        )
+    return column_type.p_from_indices(indices, default)
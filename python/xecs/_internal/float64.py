@@ -0,0 +1,13 @@
+from typing import cast
+
+from xecs.xecs import Float64
+
+
+def float64(*, default: float) -> Float64:
+    """
+    Provide additional data about a component field.
+
+    Parameters:
+        default: The default value for the field.
+    """
+    return cast(Float64, default)
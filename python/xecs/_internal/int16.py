@@ -0,0 +1,19 @@
+import typing
+
+from xecs._internal.field_options import FieldOptions
+from xecs.xecs import Int16
+
+if typing.TYPE_CHECKING:
+    from xecs.xecs import Overflow
+
+
+def int16(*, default: int, overflow: "Overflow" = "checked") -> Int16:
+    """
+    Provide additional data about a component field.
+
+    Parameters:
+        default: The default value for the field.
+        overflow: What arithmetic on the field does when a result does
+            not fit, as in :meth:`Int16.set_overflow`.
+    """
+    return typing.cast(Int16, FieldOptions.p_new(default, overflow))
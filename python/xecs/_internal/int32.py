@@ -1,13 +1,19 @@
-from typing import cast
+import typing
 
+from xecs._internal.field_options import FieldOptions
 from xecs.xecs import Int32
 
+if typing.TYPE_CHECKING:
+    from xecs.xecs import Overflow
 
-def int32(*, default: int) -> Int32:
+
+def int32(*, default: int, overflow: "Overflow" = "checked") -> Int32:
     """
     Provide additional data about a component field.
 
     Parameters:
         default: The default value for the field.
+        overflow: What arithmetic on the field does when a result does
+            not fit, as in :meth:`Int32.set_overflow`.
     """
-    return cast(Int32, default)
+    return typing.cast(Int32, FieldOptions.p_new(default, overflow))
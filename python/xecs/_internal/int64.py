@@ -0,0 +1,19 @@
+import typing
+
+from xecs._internal.field_options import FieldOptions
+from xecs.xecs import Int64
+
+if typing.TYPE_CHECKING:
+    from xecs.xecs import Overflow
+
+
+def int64(*, default: int, overflow: "Overflow" = "checked") -> Int64:
+    """
+    Provide additional data about a component field.
+
+    Parameters:
+        default: The default value for the field.
+        overflow: What arithmetic on the field does when a result does
+            not fit, as in :meth:`Int64.set_overflow`.
+    """
+    return typing.cast(Int64, FieldOptions.p_new(default, overflow))
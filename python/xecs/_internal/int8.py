@@ -0,0 +1,19 @@
+import typing
+
+from xecs._internal.field_options import FieldOptions
+from xecs.xecs import Int8
+
+if typing.TYPE_CHECKING:
+    from xecs.xecs import Overflow
+
+
+def int8(*, default: int, overflow: "Overflow" = "checked") -> Int8:
+    """
+    Provide additional data about a component field.
+
+    Parameters:
+        default: The default value for the field.
+        overflow: What arithmetic on the field does when a result does
+            not fit, as in :meth:`Int8.set_overflow`.
+    """
+    return typing.cast(Int8, FieldOptions.p_new(default, overflow))
@@ -1,15 +1,21 @@
 import typing
 
+from xecs._internal.field_options import FieldOptions
 from xecs.xecs import Int32
 
+if typing.TYPE_CHECKING:
+    from xecs.xecs import Overflow
+
 Int: typing.TypeAlias = Int32
 
 
-def int_(*, default: int) -> Int:
+def int_(*, default: int, overflow: "Overflow" = "checked") -> Int:
     """
     Provide additional data about a component field.
 
     Parameters:
         default: The default value for the field.
+        overflow: What arithmetic on the field does when a result does
+            not fit, as in :meth:`Int32.set_overflow`.
     """
-    return typing.cast(Int, default)
+    return typing.cast(Int, FieldOptions.p_new(default, overflow))
@@ -1,8 +1,5 @@
 from typing import Generic, TypeVar, cast
 
-import numpy as np
-import numpy.typing as npt
-
 from xecs import xecs
 
 T = TypeVar("T")
@@ -37,6 +34,9 @@ class PyField(Generic[T]):
     ) -> "PyField[T]":
         return PyField.p_new(self._inner.p_new_view_with_indices(indices))
 
+    def p_column(self) -> xecs.Column:
+        return self._inner.p_column()
+
     def fill(self, value: T) -> None:
         """
         Set the values of the array.
@@ -51,15 +51,21 @@ class PyField(Generic[T]):
         Get the value at a specific index.
 
         Parameters:
-            index: The index where the value is located.
+            index: The index where the value is located. Negative
+                indices count back from the end.
         Returns:
             The value at `index`.
+        Raises:
+            IndexError: If `index` is out of bounds.
         """
         return self._inner.get(index)
 
-    def __getitem__(self, key: npt.NDArray[np.bool_]) -> "PyField[T]":
+    def __getitem__(self, key: "xecs.GetItemKey") -> "PyField[T]":
         return PyField.p_new(self._inner[key])
 
+    def __setitem__(self, key: "xecs.GetItemKey", value: T) -> None:
+        self._inner[key] = value
+
     def __len__(self) -> int:
         return len(self._inner)
 
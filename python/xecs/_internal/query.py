@@ -1,19 +1,92 @@
+import math
 import typing
-from collections.abc import Sequence
+from collections.abc import Iterator, Sequence
 from typing import cast
 
+import numpy as np
+import numpy.typing as npt
+
 from xecs._internal.component import Component
-from xecs.xecs import product_2
+from xecs.xecs import (
+    KdTree,
+    SpatialGrid,
+    combinations_2,
+    combinations_2_chunks,
+    product_2,
+    product_2_chunks,
+    product_with,
+    product_with_chunks,
+)
 
 if typing.TYPE_CHECKING:
-    from xecs.xecs import QueryId
+    from xecs._internal.vec2 import Vec2
+    from xecs.xecs import ArrayViewIndices, Float32, QueryId
+
+_MIN_CELL_SIZE = 1e-6
 
 T = typing.TypeVar("T")
+U = typing.TypeVar("U")
+Filters = typing.TypeVarTuple("Filters")
+OtherFilters = typing.TypeVarTuple("OtherFilters")
+ComponentT = typing.TypeVar("ComponentT", bound=Component)
+
+
+class With(typing.Generic[ComponentT]):
+    """
+    A query filter for entities which hold a component.
+
+    The data of the component is not part of the query result.
+    """
+
+
+class Without(typing.Generic[ComponentT]):
+    """
+    A query filter for entities which do not hold a component.
+    """
 
 
-class Query(typing.Generic[T]):
+class Added(typing.Generic[ComponentT]):
+    """
+    A query filter for entities which were given a component since the
+    system last ran.
+    """
+
+
+class Changed(typing.Generic[ComponentT]):
+    """
+    A query filter for entities whose component was added or written to
+    since the system last ran.
+
+    Writes are recorded by ``fill``, ``__setitem__`` and the in-place
+    operators of each field of the component.
+    """
+
+
+class Maybe(typing.Generic[ComponentT]):
+    """
+    A query term for a component which entities may or may not hold.
+
+    In the query result, the term is a :class:`.Bool` mask which is
+    ``True`` for the matched entities which hold the component, and a
+    view of the component for those entities. For example, with
+    ``Query[tuple[Transform2, Maybe[Sprite]]]``::
+
+        transform, (has_sprite, sprite) = query.result()
+        transform_with_sprite = transform[has_sprite.numpy()]
+
+    Queries with a Maybe term cannot pair their entities or find their
+    neighbors, so methods such as :meth:`.Query.product_2` raise a
+    :class:`TypeError` for them.
+    """
+
+
+class Query(typing.Generic[T, *Filters]):
     """
     A system parameter providing selective access to component data.
+
+    Filters such as :class:`.With`, :class:`.Without`, :class:`.Added`
+    and :class:`.Changed` can follow the queried components, for example
+    ``Query[tuple[Transform2, Velocity], Without[Frozen]]``.
     """
 
     p_name: str
@@ -21,6 +94,7 @@ class Query(typing.Generic[T]):
     p_query_id: "QueryId"
     p_result: T
     p_components: Sequence[type[Component]]
+    p_optional: Sequence[bool]
     p_tuple_query: bool
 
     @classmethod
@@ -29,12 +103,14 @@ class Query(typing.Generic[T]):
         name: str,
         query_id: int,
         components: Sequence[type[Component]],
+        optional: Sequence[bool],
         tuple_query: bool,
     ) -> typing.Self:
         query = cls()
         query.p_name = name
         query.p_query_id = query_id
         query.p_components = components
+        query.p_optional = optional
         query.p_tuple_query = tuple_query
         return query
 
@@ -54,32 +130,225 @@ class Query(typing.Generic[T]):
         Returns:
             Every pair of entities.
         """
-        if self.p_tuple_query:
-            query_result = cast(Sequence[Component], self.p_result)
-        else:
-            query_result = cast(Sequence[Component], (self.p_result,))
+        indices1, indices2 = product_2(self._indices())
+        return self._view(indices1), self._view(indices2)
+
+    def combinations_2(self) -> tuple[T, T]:
+        """
+        Get every unordered pair of entities matching the query.
+
+        Unlike :meth:`product_2`, each pair appears once rather than in
+        both orders, which suits symmetric interactions such as
+        collisions.
+
+        Returns:
+            Every pair of entities.
+        """
+        indices1, indices2 = combinations_2(self._indices())
+        return self._view(indices1), self._view(indices2)
+
+    def product_with(
+        self, other: "Query[U, *OtherFilters]"
+    ) -> tuple[T, U]:
+        """
+        Pair every entity matching the query with every entity matching
+        another query.
+
+        For example, ``bullets.product_with(enemies)`` gives every bullet
+        against every enemy.
+
+        Parameters:
+            other: The other query.
+        Returns:
+            The component data of this query and the other, for every
+            pair of entities.
+        """
+        indices1, indices2 = product_with(
+            self._indices(), other._indices()
+        )
+        return self._view(indices1), other._view(indices2)
 
-        indices1, indices2 = product_2(
-            [component.p_indices for component in query_result]
+    def product_2_chunks(self, chunk_size: int) -> Iterator[tuple[T, T]]:
+        """
+        Like :meth:`product_2`, but give the pairs in chunks.
+
+        Only one chunk of pairs is held in memory at a time, so this can
+        go through the pairs of many more entities.
+
+        Parameters:
+            chunk_size: The largest number of pairs in a chunk.
+        Returns:
+            The pairs of entities, one chunk at a time.
+        """
+        chunks = product_2_chunks(self._indices(), chunk_size)
+        return (
+            (self._view(indices1), self._view(indices2))
+            for indices1, indices2 in chunks
+        )
+
+    def combinations_2_chunks(
+        self, chunk_size: int
+    ) -> Iterator[tuple[T, T]]:
+        """
+        Like :meth:`combinations_2`, but give the pairs in chunks.
+
+        Parameters:
+            chunk_size: The largest number of pairs in a chunk.
+        Returns:
+            The pairs of entities, one chunk at a time.
+        """
+        chunks = combinations_2_chunks(self._indices(), chunk_size)
+        return (
+            (self._view(indices1), self._view(indices2))
+            for indices1, indices2 in chunks
         )
-        return cast(
-            tuple[T, T],
-            (
-                tuple(
-                    component.p_new_view_with_indices(indices)
-                    for component, indices in zip(
-                        query_result, indices1, strict=True
-                    )
-                ),
-                tuple(
-                    component.p_new_view_with_indices(indices)
-                    for component, indices in zip(
-                        query_result, indices2, strict=True
-                    )
-                ),
-            ),
+
+    def product_with_chunks(
+        self,
+        other: "Query[U, *OtherFilters]",
+        chunk_size: int,
+    ) -> Iterator[tuple[T, U]]:
+        """
+        Like :meth:`product_with`, but give the pairs in chunks.
+
+        Parameters:
+            other: The other query.
+            chunk_size: The largest number of pairs in a chunk.
+        Returns:
+            The pairs of entities, one chunk at a time.
+        """
+        chunks = product_with_chunks(
+            self._indices(), other._indices(), chunk_size
+        )
+        return (
+            (self._view(indices1), other._view(indices2))
+            for indices1, indices2 in chunks
         )
 
+    def pairs_within(
+        self,
+        position: "Vec2 | tuple[Float32, Float32]",
+        radius: float,
+    ) -> tuple[T, T]:
+        """
+        Get every pair of entities matching the query which are at most
+        `radius` apart.
+
+        This gives the pairs of :meth:`product_2` which are close
+        together, but uses a :class:`.SpatialGrid` to find them, so it
+        does not need to go through every pair.
+
+        Parameters:
+            position: The position of each entity, in the same order as
+                the query result. Either a :class:`.Vec2` or a tuple of
+                x and y values.
+            radius: The largest distance between paired entities.
+        Returns:
+            Every pair of entities which are close together.
+        Raises:
+            ValueError: If `radius` is negative or not finite.
+        """
+        if not (math.isfinite(radius) and radius >= 0):
+            raise ValueError("radius must be non-negative and finite")
+        x, y = _coordinates(position)
+        # The cells of the grid must have a positive size. A radius of
+        # zero only pairs entities at the same position, which are always
+        # in the same cell, so any small size works for it.
+        grid = SpatialGrid(x, y, max(radius, _MIN_CELL_SIZE))
+        indices1, indices2 = grid.pairs_within(radius, self._indices())
+        return self._view(indices1), self._view(indices2)
+
+    def nearest(
+        self,
+        position: "Vec2 | tuple[Float32, Float32]",
+        k: int,
+    ) -> tuple[T, T, npt.NDArray[np.float32]]:
+        """
+        Pair every entity matching the query with the `k` nearest other
+        entities.
+
+        The neighbors of each entity are next to each other and sorted
+        by distance, so the ``i``-th entity of the query result is the
+        first of the pairs at positions ``i * k`` to ``(i + 1) * k``.
+
+        Parameters:
+            position: The position of each entity, in the same order as
+                the query result. Either a :class:`.Vec2` or a tuple of
+                x and y values.
+            k: The number of neighbors of each entity. Must be less than
+                the number of entities.
+        Returns:
+            The first and the second entity of every pair, and the
+            distance between them.
+        """
+        tree = KdTree(*_coordinates(position))
+        (indices1, indices2), distances = tree.nearest(k, self._indices())
+        return self._view(indices1), self._view(indices2), distances
+
+    def nearest_to(
+        self,
+        position: "Vec2 | tuple[Float32, Float32]",
+        points: "Vec2 | tuple[Float32, Float32]",
+        k: int,
+    ) -> tuple[T, npt.NDArray[np.float32]]:
+        """
+        Get the `k` entities matching the query which are nearest to
+        each of `points`.
+
+        The neighbors of each point are next to each other and sorted by
+        distance, so the neighbors of the ``i``-th point are at
+        positions ``i * k`` to ``(i + 1) * k``.
+
+        Parameters:
+            position: The position of each entity, in the same order as
+                the query result. Either a :class:`.Vec2` or a tuple of
+                x and y values.
+            points: The points to find the neighbors of, in the same
+                form as `position`.
+            k: The number of neighbors of each point. Must be at most
+                the number of entities.
+        Returns:
+            The neighbors of every point, and their distances.
+        """
+        tree = KdTree(*_coordinates(position))
+        x, y = _coordinates(points)
+        indices, distances = tree.nearest_to(x, y, k, self._indices())
+        return self._view(indices), distances
+
+    def _components(self) -> Sequence[Component]:
+        # A Maybe term is a mask and a view of only some of the entities,
+        # so it cannot be lined up with the pairs of entities.
+        if any(self.p_optional):
+            raise TypeError(
+                f"{self.p_name} has a Maybe term, which is not supported "
+                "when pairing entities or finding neighbors"
+            )
+        if self.p_tuple_query:
+            return cast(Sequence[Component], self.p_result)
+        return (cast(Component, self.p_result),)
+
+    def _indices(self) -> list["ArrayViewIndices"]:
+        return [component.p_indices for component in self._components()]
+
+    def _view(self, indices: list["ArrayViewIndices"]) -> T:
+        components = tuple(
+            component.p_new_view_with_indices(component_indices)
+            for component, component_indices in zip(
+                self._components(), indices, strict=True
+            )
+        )
+        if self.p_tuple_query:
+            return cast(T, components)
+        return cast(T, components[0])
+
     def __class_getitem__(cls, key: typing.Any) -> typing.Any:
         cls.p_num_queries += 1
         return super().__class_getitem__(key)  # type: ignore
+
+
+def _coordinates(
+    position: "Vec2 | tuple[Float32, Float32]",
+) -> tuple["Float32", "Float32"]:
+    if isinstance(position, tuple):
+        return position
+    return position.x, position.y
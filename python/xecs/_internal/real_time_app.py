@@ -14,7 +14,15 @@ from xecs._internal.component import (
 from xecs._internal.entity_id import EntityId
 from xecs._internal.events import EventReader, Events, EventWriter
 from xecs._internal.input import Keyboard, Mouse
-from xecs._internal.query import Query
+from xecs._internal.query import (
+    Added,
+    Changed,
+    Maybe,
+    Query,
+    With,
+    Without,
+)
+from xecs._internal.removed_components import RemovedComponents
 from xecs._internal.resource import Resource
 from xecs._internal.systems import (
     FixedTimeStepSystems,
@@ -31,7 +39,10 @@ from xecs._internal.systems import (
 )
 from xecs._internal.time import Time
 from xecs._internal.world import World
-from xecs.xecs import Duration, Instant, RustApp
+from xecs.xecs import Bool, Duration, Instant, RustApp
+
+if typing.TYPE_CHECKING:
+    from xecs.xecs import ComponentId
 
 P = typing.ParamSpec("P")
 R = typing.TypeVar("R")
@@ -58,9 +69,11 @@ class RealTimeApp:
 
     Parameters:
         num_entities: The maximum number of entities which can be spawned.
+        growable: Whether more than `num_entities` entities can be
+            spawned, by growing the pool of entity ids.
     """
 
-    def __init__(self, num_entities: int) -> None:
+    def __init__(self, num_entities: int, *, growable: bool = False) -> None:
         self.world = World()
         self.add_resource(PendingStartupSystems([]))
         self.add_resource(StartupSystems([]))
@@ -78,7 +91,7 @@ class RealTimeApp:
         self._commands = Commands.p_new(self._rust_app, self.world)
         self._has_run_startup_systems = False
 
-        self.add_pool(EntityId.create_pool(num_entities))
+        self.add_pool(EntityId.create_pool(num_entities, growable=growable))
 
     def add_plugin(self, plugin: RealTimeAppPlugin) -> None:
         """
@@ -132,46 +145,61 @@ class RealTimeApp:
     ) -> tuple[
         dict[str, Query[Any]],
         dict[str, EventReader[Any]],
+        dict[str, RemovedComponents[Any]],
         dict[str, OtherParameter],
     ]:
         query_args: dict[str, Query[Any]] = {}
         reader_args: dict[str, EventReader[Any]] = {}
+        removed_args: dict[str, RemovedComponents[Any]] = {}
         other_args: dict[str, OtherParameter] = {}
         for name, parameter in inspect.signature(system).parameters.items():
             origin = typing.get_origin(parameter.annotation)
             if origin is Query:
-                (component_tuple,) = typing.get_args(parameter.annotation)
-                if issubclass(component_tuple, Component):
-                    query_id = self._rust_app.add_query(
-                        first_component=Component.component_ids[
-                            component_tuple
-                        ],
-                        other_components=[],
-                    )
-                    query_args[name] = Query.p_new(
-                        str(parameter.annotation),
-                        query_id,
-                        [component_tuple],
-                        False,
+                component_tuple, *filters = typing.get_args(
+                    parameter.annotation
+                )
+                (
+                    with_components,
+                    without_components,
+                    added_components,
+                    changed_components,
+                ) = self._get_filters(system, name, filters)
+                components, optional = self._get_terms(component_tuple)
+                component_ids = [
+                    Component.component_ids[component]
+                    for component, is_optional in zip(
+                        components, optional, strict=True
                     )
-
-                else:
-                    components = typing.get_args(component_tuple)
-                    component_ids = [
-                        Component.component_ids[component]
-                        for component in components
-                    ]
-
-                    query_id = self._rust_app.add_query(
-                        first_component=component_ids[0],
-                        other_components=component_ids[1:],
+                    if not is_optional
+                ]
+                optional_component_ids = [
+                    Component.component_ids[component]
+                    for component, is_optional in zip(
+                        components, optional, strict=True
                     )
-                    query_args[name] = Query.p_new(
-                        str(parameter.annotation),
-                        query_id,
-                        components,
-                        True,
+                    if is_optional
+                ]
+                if not component_ids:
+                    raise SystemSignatureError(
+                        f'query parameter "{name}" in "{system.__name__}" '
+                        "needs at least one component which is not Maybe"
                     )
+                query_id = self._rust_app.add_query(
+                    first_component=component_ids[0],
+                    other_components=component_ids[1:],
+                    with_components=with_components,
+                    without_components=without_components,
+                    optional_components=optional_component_ids,
+                    added_components=added_components,
+                    changed_components=changed_components,
+                )
+                query_args[name] = Query.p_new(
+                    str(parameter.annotation),
+                    query_id,
+                    components,
+                    optional,
+                    typing.get_origin(component_tuple) is tuple,
+                )
             elif origin is EventReader:
                 (event_type,) = typing.get_args(parameter.annotation)
                 reader: EventReader[Any] = EventReader()
@@ -182,6 +210,14 @@ class RealTimeApp:
                 writer = events.writers[event_type]
                 writer.p_readers.append(reader)
 
+            elif origin is RemovedComponents:
+                (component,) = typing.get_args(parameter.annotation)
+                removed_args[name] = RemovedComponents(
+                    self._rust_app.add_removed_reader(
+                        Component.component_ids[component]
+                    )
+                )
+
             elif origin is EventWriter:
                 (event_type,) = typing.get_args(parameter.annotation)
                 events = self.world.get_resource(Events)
@@ -207,25 +243,97 @@ class RealTimeApp:
                     "but needs to be "
                     f"{expected_type}"
                 )
-        return query_args, reader_args, other_args
+        return query_args, reader_args, removed_args, other_args
+
+    @staticmethod
+    def _get_terms(
+        component_tuple: Any,
+    ) -> tuple[list[type[Component]], list[bool]]:
+        if typing.get_origin(component_tuple) is tuple:
+            terms = typing.get_args(component_tuple)
+        else:
+            terms = (component_tuple,)
+        components = []
+        optional = []
+        for term in terms:
+            if typing.get_origin(term) is Maybe:
+                (component,) = typing.get_args(term)
+                components.append(component)
+                optional.append(True)
+            else:
+                components.append(term)
+                optional.append(False)
+        return components, optional
+
+    @staticmethod
+    def _get_filters(
+        system: abc.Callable[P, R],
+        name: str,
+        filters: abc.Iterable[Any],
+    ) -> tuple[
+        list["ComponentId"],
+        list["ComponentId"],
+        list["ComponentId"],
+        list["ComponentId"],
+    ]:
+        filter_components: dict[type, list["ComponentId"]] = {
+            With: [],
+            Without: [],
+            Added: [],
+            Changed: [],
+        }
+        for query_filter in filters:
+            filter_origin = typing.get_origin(query_filter)
+            if filter_origin not in filter_components:
+                raise SystemSignatureError(
+                    f'query parameter "{name}" in "{system.__name__}" '
+                    f'has filter "{query_filter}" but filters need to be '
+                    "With | Without | Added | Changed"
+                )
+            (component,) = typing.get_args(query_filter)
+            filter_components[filter_origin].append(
+                Component.component_ids[component]
+            )
+        return (
+            filter_components[With],
+            filter_components[Without],
+            filter_components[Added],
+            filter_components[Changed],
+        )
 
     def _run_query(self, query: Query[Any]) -> None:
         self._assert_has_pools(query.p_components)
-        component_indices = self._rust_app.run_query(query.p_query_id)
-        query.p_result = tuple(
-            pool.p_component.p_new_view_with_indices(indices)
-            for pool, indices in zip(
-                (
-                    self.world.p_get_pool(component)
-                    for component in query.p_components
-                ),
-                iter(lambda: component_indices.next(), None),
-                strict=True,
-            )
+        component_indices, optional_results = self._rust_app.run_query(
+            query.p_query_id
         )
+        required_indices = iter(lambda: component_indices.next(), None)
+        optional_indices = iter(optional_results)
+        result: list[Any] = []
+        for component, is_optional in zip(
+            query.p_components, query.p_optional, strict=True
+        ):
+            pool = self.world.p_get_pool(component)
+            if is_optional:
+                mask, indices = next(optional_indices)
+                result.append(
+                    (
+                        Bool.p_from_numpy(mask),
+                        pool.p_component.p_new_view_with_indices(indices),
+                    )
+                )
+            else:
+                result.append(
+                    pool.p_component.p_new_view_with_indices(
+                        next(required_indices)
+                    )
+                )
+        query.p_result = tuple(result)
         if not query.p_tuple_query:
             query.p_result = query.p_result[0]
 
+    def _read_removed(self, removed: RemovedComponents[Any]) -> None:
+        removed.entity_ids = self._rust_app.read_removed(removed.p_reader_id)
+
     def _assert_has_pools(
         self,
         components: abc.Iterable[type[Component]],
@@ -244,12 +352,18 @@ class RealTimeApp:
         )
         startup_systems = self.world.get_resource(StartupSystems)
         for system in pending_startup_systems.systems:
-            query_args, reader_args, other_args = self._get_system_args(system)
+            (
+                query_args,
+                reader_args,
+                removed_args,
+                other_args,
+            ) = self._get_system_args(system)
             startup_systems.systems.append(
                 SystemSpec(
                     function=system,
                     query_args=query_args,
                     reader_args=reader_args,
+                    removed_args=removed_args,
                     other_args=other_args,
                 )
             )
@@ -260,7 +374,12 @@ class RealTimeApp:
         systems = self.world.get_resource(Systems)
         fixed_time_step_systems = self.world.get_resource(FixedTimeStepSystems)
         for system, run_condition in pending_systems.systems:
-            query_args, reader_args, other_args = self._get_system_args(system)
+            (
+                query_args,
+                reader_args,
+                removed_args,
+                other_args,
+            ) = self._get_system_args(system)
 
             match run_condition:
                 case Duration():
@@ -269,25 +388,36 @@ class RealTimeApp:
                             system,
                             query_args,
                             reader_args,
+                            removed_args,
                             other_args,
                             run_condition,
                         )
                     )
                 case None:
                     systems.systems.append(
-                        SystemSpec(system, query_args, reader_args, other_args)
+                        SystemSpec(
+                            system,
+                            query_args,
+                            reader_args,
+                            removed_args,
+                            other_args,
+                        )
                     )
         pending_systems.systems = []
 
     def _run_startup_systems(self) -> None:
         self._has_run_startup_systems = True
         for system in self.world.get_resource(StartupSystems).systems:
+            self._rust_app.advance_tick()
             for query in system.query_args.values():
                 self._run_query(query)
+            for removed in system.removed_args.values():
+                self._read_removed(removed)
 
             system.function(
                 **system.query_args,
                 **system.reader_args,
+                **system.removed_args,
                 **system.other_args,
             )
             for reader in system.reader_args.values():
@@ -295,12 +425,16 @@ class RealTimeApp:
 
     def _run_systems(self) -> None:
         for system in self.world.get_resource(Systems).systems:
+            self._rust_app.advance_tick()
             for query in system.query_args.values():
                 self._run_query(query)
+            for removed in system.removed_args.values():
+                self._read_removed(removed)
 
             system.function(
                 **system.query_args,
                 **system.reader_args,
+                **system.removed_args,
                 **system.other_args,
             )
             for reader in system.reader_args.values():
@@ -313,12 +447,16 @@ class RealTimeApp:
         for system in self.world.get_resource(FixedTimeStepSystems).systems:
             system.time_to_simulate += time_since_last_update
             while system.time_to_simulate >= system.time_step:
+                self._rust_app.advance_tick()
                 for query in system.query_args.values():
                     self._run_query(query)
+                for removed in system.removed_args.values():
+                    self._read_removed(removed)
 
                 system.function(
                     **system.query_args,
                     **system.reader_args,
+                    **system.removed_args,
                     **system.other_args,
                 )
                 system.time_to_simulate -= system.time_step
@@ -389,5 +527,11 @@ class RealTimeApp:
             pool: The component pool.
         """
         component_id = Component.component_ids[type(pool.p_component)]
-        self._rust_app.add_pool(component_id, pool.p_capacity)
+        self._rust_app.add_pool(
+            component_id,
+            pool.p_component.p_indices,
+            pool.p_component.p_columns(),
+            pool.p_growth_factor,
+            pool.p_max_capacity,
+        )
         self.world.add_pool(pool)
@@ -0,0 +1,33 @@
+import typing
+from typing import Generic, TypeVar
+
+import numpy as np
+import numpy.typing as npt
+
+from xecs._internal.component import Component
+
+if typing.TYPE_CHECKING:
+    from xecs.xecs import RemovedReaderId
+
+ComponentT = TypeVar("ComponentT", bound=Component)
+
+
+class RemovedComponents(Generic[ComponentT]):
+    """
+    Gives access to the entities which lost a component of type
+    ``ComponentT``, either because it was removed or because the
+    entity was despawned.
+    """
+
+    __slots__ = ("entity_ids", "p_reader_id")
+
+    entity_ids: npt.NDArray[np.uint32]
+    """
+    The IDs of entities which lost the component since the last time
+    the system was called.
+    """
+    p_reader_id: "RemovedReaderId"
+
+    def __init__(self, reader_id: "RemovedReaderId") -> None:
+        self.entity_ids = np.empty(0, dtype=np.uint32)
+        self.p_reader_id = reader_id
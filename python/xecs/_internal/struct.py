@@ -1,11 +1,12 @@
 import inspect
 import typing
 
-import numpy as np
-import numpy.typing as npt
-
+from xecs._internal.field_options import p_column_from_indices
 from xecs._internal.py_field import PyField, PyFieldError
-from xecs.xecs import ArrayViewIndices
+from xecs.xecs import ArrayViewIndices, Column
+
+if typing.TYPE_CHECKING:
+    from xecs.xecs import GetItemKey
 
 
 class Struct:
@@ -43,13 +44,15 @@ class Struct:
                 setattr(
                     struct,
                     key,
-                    value.p_from_indices(
-                        indices, getattr(cls, key, value.p_default_value())
+                    p_column_from_indices(
+                        value,
+                        indices,
+                        getattr(cls, key, value.p_default_value()),
                     ),
                 )
         return struct
 
-    def __getitem__(self, key: npt.NDArray[np.bool_]) -> typing.Self:
+    def __getitem__(self, key: "GetItemKey") -> typing.Self:
         cls = self.__class__
         struct = cls()
         struct._indices = self._indices[key]
@@ -62,6 +65,16 @@ class Struct:
             )
         return struct
 
+    def p_columns(self) -> list[Column]:
+        columns = []
+        for attr_name in inspect.get_annotations(type(self)):
+            attr_value = getattr(self, attr_name)
+            if isinstance(attr_value, Struct):
+                columns.extend(attr_value.p_columns())
+            else:
+                columns.append(attr_value.p_column())
+        return columns
+
     def p_new_view_with_indices(
         self,
         indices: ArrayViewIndices,
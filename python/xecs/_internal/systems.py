@@ -4,6 +4,7 @@ from typing import Any, TypeAlias
 from xecs._internal.commands import Commands
 from xecs._internal.events import EventReader, EventWriter
 from xecs._internal.query import Query
+from xecs._internal.removed_components import RemovedComponents
 from xecs._internal.resource import Resource
 from xecs._internal.world import World
 from xecs.xecs import Duration
@@ -23,7 +24,13 @@ class SystemSpec:
     Specification for a system.
     """
 
-    __slots__ = "function", "query_args", "reader_args", "other_args"
+    __slots__ = (
+        "function",
+        "query_args",
+        "reader_args",
+        "removed_args",
+        "other_args",
+    )
 
     function: System
     """The function which runs the system."""
@@ -31,6 +38,8 @@ class SystemSpec:
     """The query arguments for the system."""
     reader_args: dict[str, EventReader[Any]]
     """The event reader arguments for the system."""
+    removed_args: dict[str, RemovedComponents[Any]]
+    """The removed component arguments for the system."""
     other_args: dict[str, OtherParameter]
     """The other arguments for the system."""
 
@@ -39,6 +48,7 @@ class SystemSpec:
         function: System,
         query_args: dict[str, Query[Any]],
         reader_args: dict[str, EventReader[Any]],
+        removed_args: dict[str, RemovedComponents[Any]],
         other_args: dict[str, OtherParameter],
     ) -> None:
         """
@@ -46,11 +56,13 @@ class SystemSpec:
             function: The function which runs the system.
             query_args: The query arguments for the system.
             reader_args: The event reader arguments for the system.
+            removed_args: The removed component arguments for the system.
             other_args: The other arguments for the system.
         """
         self.function = function
         self.query_args = query_args
         self.reader_args = reader_args
+        self.removed_args = removed_args
         self.other_args = other_args
 
 
@@ -63,6 +75,7 @@ class FixedTimeStepSystemSpec:
         "function",
         "query_args",
         "reader_args",
+        "removed_args",
         "other_args",
         "time_step",
         "time_to_simulate",
@@ -74,6 +87,8 @@ class FixedTimeStepSystemSpec:
     """The query arguments for the system."""
     reader_args: dict[str, EventReader[Any]]
     """The event reader arguments for the system."""
+    removed_args: dict[str, RemovedComponents[Any]]
+    """The removed component arguments for the system."""
     other_args: dict[str, OtherParameter]
     """The other arguments for the system."""
     time_step: Duration
@@ -86,6 +101,7 @@ class FixedTimeStepSystemSpec:
         function: System,
         query_args: dict[str, Query[Any]],
         reader_args: dict[str, EventReader[Any]],
+        removed_args: dict[str, RemovedComponents[Any]],
         other_args: dict[str, OtherParameter],
         time_step: Duration,
     ) -> None:
@@ -94,12 +110,14 @@ class FixedTimeStepSystemSpec:
             function: The function which runs the system.
             query_args: The query arguments for the system.
             reader_args: The event reader arguments for the system.
+            removed_args: The removed component arguments for the system.
             other_args: The other arguments for the system.
             time_step: The time span between runs of the system.
         """
         self.function = function
         self.query_args = query_args
         self.reader_args = reader_args
+        self.removed_args = removed_args
         self.other_args = other_args
         self.time_step = time_step
         self.time_to_simulate = Duration.new(0, 0)
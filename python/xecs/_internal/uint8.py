@@ -0,0 +1,19 @@
+import typing
+
+from xecs._internal.field_options import FieldOptions
+from xecs.xecs import UInt8
+
+if typing.TYPE_CHECKING:
+    from xecs.xecs import Overflow
+
+
+def uint8(*, default: int, overflow: "Overflow" = "checked") -> UInt8:
+    """
+    Provide additional data about a component field.
+
+    Parameters:
+        default: The default value for the field.
+        overflow: What arithmetic on the field does when a result does
+            not fit, as in :meth:`UInt8.set_overflow`.
+    """
+    return typing.cast(UInt8, FieldOptions.p_new(default, overflow))
@@ -62,6 +62,7 @@ class World:
             pool: The component pool to add.
         """
         component = type(pool.p_component)
+        pool.p_in_app = True
         self._pools[component] = cast(ComponentPool[Component], pool)
 
     def has_pool(self, component: type[Component]) -> bool:
@@ -4,7 +4,15 @@ import numpy as np
 import numpy.typing as npt
 
 QueryId: typing.TypeAlias = int
+RemovedReaderId: typing.TypeAlias = int
 ComponentId: typing.TypeAlias = int
+GetItemKey: typing.TypeAlias = (
+    int
+    | slice
+    | npt.NDArray[np.bool_]
+    | npt.NDArray[np.integer[typing.Any]]
+    | list[int]
+)
 
 class MultipleArrayInidices:
     def next(self) -> ArrayViewIndices | None: ...
@@ -13,29 +21,70 @@ class RustApp:
     def __init__(self, num_pools: int, num_queries: int) -> None: ...
     def spawn(
         self, components: list[ComponentId], num: int
-    ) -> npt.NDArray[np.uint32]: ...
+    ) -> tuple[npt.NDArray[np.uint32], list[ArrayViewIndices]]: ...
+    def despawn(self, entity_ids: npt.NDArray[np.uint32]) -> None: ...
+    def insert_components(
+        self,
+        entity_ids: npt.NDArray[np.uint32],
+        components: list[ComponentId],
+    ) -> list[ArrayViewIndices]: ...
+    def remove_components(
+        self,
+        entity_ids: npt.NDArray[np.uint32],
+        components: list[ComponentId],
+    ) -> None: ...
+    def is_alive(
+        self, entity_ids: npt.NDArray[np.uint32]
+    ) -> npt.NDArray[np.bool_]: ...
     def add_pool(
         self,
         component_id: ComponentId,
-        capacity: int,
+        indices: ArrayViewIndices,
+        columns: list[Column],
+        growth_factor: float | None,
+        max_capacity: int | None,
     ) -> None: ...
     def add_query(
         self,
         first_component: ComponentId,
         other_components: list[ComponentId],
+        with_components: list[ComponentId],
+        without_components: list[ComponentId],
+        optional_components: list[ComponentId],
+        added_components: list[ComponentId],
+        changed_components: list[ComponentId],
     ) -> QueryId: ...
-    def run_query(self, query_id: QueryId) -> MultipleArrayInidices: ...
+    def run_query(
+        self, query_id: QueryId
+    ) -> tuple[
+        MultipleArrayInidices,
+        list[tuple[npt.NDArray[np.bool_], ArrayViewIndices]],
+    ]: ...
+    def add_removed_reader(
+        self, component_id: ComponentId
+    ) -> RemovedReaderId: ...
+    def read_removed(
+        self, reader_id: RemovedReaderId
+    ) -> npt.NDArray[np.uint32]: ...
+    def advance_tick(self) -> None: ...
 
 class ArrayViewIndices:
     @staticmethod
     def with_capacity(capacity: int) -> ArrayViewIndices: ...
     def spawn(self, num: int) -> ArrayViewIndices: ...
     def __len__(self) -> int: ...
-    def __getitem__(self, key: npt.NDArray[np.bool_]) -> ArrayViewIndices: ...
+    def __getitem__(self, key: GetItemKey) -> ArrayViewIndices: ...
+
+class Column: ...
+
+Overflow: typing.TypeAlias = typing.Literal[
+    "checked", "wrapping", "saturating"
+]
 
 Float32Rhs: typing.TypeAlias = (
     float | Float32 | npt.NDArray[np.float32] | list[float] | tuple[float, ...]
 )
+Float32ArithRhs: typing.TypeAlias = Float32Rhs | Bool
 
 T = typing.TypeVar("T")
 
@@ -48,9 +97,11 @@ class PyField(typing.Generic[T]):
         self,
         indices: ArrayViewIndices,
     ) -> typing.Self: ...
+    def p_column(self) -> Column: ...
     def fill(self, value: T) -> None: ...
     def get(self, index: int) -> T: ...
-    def __getitem__(self, key: npt.NDArray[np.bool_]) -> PyField[T]: ...
+    def __getitem__(self, key: GetItemKey) -> PyField[T]: ...
+    def __setitem__(self, key: GetItemKey, value: T) -> None: ...
     def __len__(self) -> int: ...
 
 class Float32:
@@ -70,41 +121,417 @@ class Float32:
         self,
         indices: ArrayViewIndices,
     ) -> typing.Self: ...
+    def p_column(self) -> Column: ...
     def get(self, index: int) -> float: ...
     def to_str(self) -> str: ...
-    def __getitem__(self, key: npt.NDArray[np.bool_]) -> Float32: ...
+    def __getitem__(self, key: GetItemKey) -> Float32: ...
     def __setitem__(
         self,
-        key: npt.NDArray[np.bool_],
+        key: GetItemKey,
         value: Float32Rhs,
     ) -> None: ...
     def __len__(self) -> int: ...
-    def __add__(self, other: Float32Rhs) -> npt.NDArray[np.float32]: ...
-    def __iadd__(self, other: Float32Rhs) -> Float32: ...
-    def __sub__(self, other: Float32Rhs) -> npt.NDArray[np.float32]: ...
-    def __isub__(self, other: Float32Rhs) -> Float32: ...
-    def __mul__(self, other: Float32Rhs) -> npt.NDArray[np.float32]: ...
-    def __imul__(self, other: Float32Rhs) -> Float32: ...
-    def __truediv__(self, other: Float32Rhs) -> npt.NDArray[np.float32]: ...
-    def __itruediv__(self, other: Float32Rhs) -> Float32: ...
-    def __floordiv__(self, other: Float32Rhs) -> npt.NDArray[np.float32]: ...
-    def __ifloordiv__(self, other: Float32Rhs) -> Float32: ...
-    def __mod__(self, other: Float32Rhs) -> npt.NDArray[np.float32]: ...
-    def __imod__(self, other: Float32Rhs) -> Float32: ...
-    def __pow__(self, other: Float32Rhs) -> npt.NDArray[np.float32]: ...
-    def __ipow__(self, other: Float32Rhs) -> Float32: ...
-    def __lt__(self, other: Float32Rhs) -> npt.NDArray[np.bool_]: ...
-    def __le__(self, other: Float32Rhs) -> npt.NDArray[np.bool_]: ...
-    def __gt__(self, other: Float32Rhs) -> npt.NDArray[np.bool_]: ...
-    def __ge__(self, other: Float32Rhs) -> npt.NDArray[np.bool_]: ...
-    def __eq__(self, other: Float32Rhs) -> npt.NDArray[np.bool_]: ...  # type: ignore
-    def __ne__(self, other: Float32Rhs) -> npt.NDArray[np.bool_]: ...  # type: ignore
+    @typing.overload
+    def __add__(self, other: Float32ArithRhs) -> npt.NDArray[np.float32]: ...
+    @typing.overload
+    def __add__(
+        self, other: Float64 | Int32 | UInt32
+    ) -> npt.NDArray[np.float64]: ...
+    def __iadd__(
+        self, other: Float32ArithRhs | Float64 | Int32 | UInt32
+    ) -> Float32: ...
+    @typing.overload
+    def __sub__(self, other: Float32ArithRhs) -> npt.NDArray[np.float32]: ...
+    @typing.overload
+    def __sub__(
+        self, other: Float64 | Int32 | UInt32
+    ) -> npt.NDArray[np.float64]: ...
+    def __isub__(
+        self, other: Float32ArithRhs | Float64 | Int32 | UInt32
+    ) -> Float32: ...
+    @typing.overload
+    def __mul__(self, other: Float32ArithRhs) -> npt.NDArray[np.float32]: ...
+    @typing.overload
+    def __mul__(
+        self, other: Float64 | Int32 | UInt32
+    ) -> npt.NDArray[np.float64]: ...
+    def __imul__(
+        self, other: Float32ArithRhs | Float64 | Int32 | UInt32
+    ) -> Float32: ...
+    @typing.overload
+    def __truediv__(
+        self, other: Float32ArithRhs
+    ) -> npt.NDArray[np.float32]: ...
+    @typing.overload
+    def __truediv__(
+        self, other: Float64 | Int32 | UInt32
+    ) -> npt.NDArray[np.float64]: ...
+    def __itruediv__(
+        self, other: Float32ArithRhs | Float64 | Int32 | UInt32
+    ) -> Float32: ...
+    @typing.overload
+    def __floordiv__(
+        self, other: Float32ArithRhs
+    ) -> npt.NDArray[np.float32]: ...
+    @typing.overload
+    def __floordiv__(
+        self, other: Float64 | Int32 | UInt32
+    ) -> npt.NDArray[np.float64]: ...
+    def __ifloordiv__(
+        self, other: Float32ArithRhs | Float64 | Int32 | UInt32
+    ) -> Float32: ...
+    @typing.overload
+    def __mod__(self, other: Float32ArithRhs) -> npt.NDArray[np.float32]: ...
+    @typing.overload
+    def __mod__(
+        self, other: Float64 | Int32 | UInt32
+    ) -> npt.NDArray[np.float64]: ...
+    def __imod__(
+        self, other: Float32ArithRhs | Float64 | Int32 | UInt32
+    ) -> Float32: ...
+    def __pow__(
+        self, other: Float32Rhs | Int32
+    ) -> npt.NDArray[np.float32]: ...
+    def __ipow__(self, other: Float32Rhs | Int32) -> Float32: ...
+    def __lt__(
+        self, other: Float32ArithRhs | Float64 | Int32 | UInt32
+    ) -> npt.NDArray[np.bool_]: ...
+    def __le__(
+        self, other: Float32ArithRhs | Float64 | Int32 | UInt32
+    ) -> npt.NDArray[np.bool_]: ...
+    def __gt__(
+        self, other: Float32ArithRhs | Float64 | Int32 | UInt32
+    ) -> npt.NDArray[np.bool_]: ...
+    def __ge__(
+        self, other: Float32ArithRhs | Float64 | Int32 | UInt32
+    ) -> npt.NDArray[np.bool_]: ...
+    def __eq__(self, other: Float32ArithRhs | Float64 | Int32 | UInt32) -> npt.NDArray[np.bool_]: ...  # type: ignore
+    def __ne__(self, other: Float32ArithRhs | Float64 | Int32 | UInt32) -> npt.NDArray[np.bool_]: ...  # type: ignore
+    def clamp(self, min: float, max: float) -> npt.NDArray[np.float32]: ...
+    def clamp_in_place(self, min: float, max: float) -> None: ...
+    def sum(self) -> float: ...
+    def mean(self) -> float: ...
+    def min(self) -> float: ...
+    def max(self) -> float: ...
+    def argmin(self) -> int: ...
+    def argmax(self) -> int: ...
+    def any(self) -> bool: ...
+    def all(self) -> bool: ...
+    def __neg__(self) -> npt.NDArray[np.float32]: ...
+    def neg_in_place(self) -> None: ...
+    def __abs__(self) -> npt.NDArray[np.float32]: ...
+    def abs_in_place(self) -> None: ...
+    def __round__(
+        self,
+        ndigits: int | None = None,
+    ) -> npt.NDArray[np.float32]: ...
+    def round_in_place(self, ndigits: int | None = None) -> None: ...
+    def sqrt(self) -> npt.NDArray[np.float32]: ...
+    def sqrt_in_place(self) -> None: ...
+    def exp(self) -> npt.NDArray[np.float32]: ...
+    def exp_in_place(self) -> None: ...
+    def log(self) -> npt.NDArray[np.float32]: ...
+    def log_in_place(self) -> None: ...
+    def sin(self) -> npt.NDArray[np.float32]: ...
+    def sin_in_place(self) -> None: ...
+    def cos(self) -> npt.NDArray[np.float32]: ...
+    def cos_in_place(self) -> None: ...
+    def atan2(self, other: Float32Rhs) -> npt.NDArray[np.float32]: ...
+    def atan2_in_place(self, other: Float32Rhs) -> None: ...
+    def floor(self) -> npt.NDArray[np.float32]: ...
+    def floor_in_place(self) -> None: ...
+    def ceil(self) -> npt.NDArray[np.float32]: ...
+    def ceil_in_place(self) -> None: ...
+
+Float64Rhs: typing.TypeAlias = (
+    float | Float64 | npt.NDArray[np.float64] | list[float] | tuple[float, ...]
+)
+Float64ArithRhs: typing.TypeAlias = (
+    Float64Rhs | Float32 | Int32 | UInt32 | Bool
+)
+
+class Float64:
+    @staticmethod
+    def p_default_value() -> float: ...
+    @staticmethod
+    def p_from_value(value: float, num: int) -> Float64: ...
+    @staticmethod
+    def p_from_numpy(array: npt.NDArray[np.float64]) -> Float64: ...
+    @staticmethod
+    def p_from_indices(
+        indices: ArrayViewIndices, default: float
+    ) -> Float64: ...
+    def numpy(self) -> npt.NDArray[np.float64]: ...
+    def fill(self, values: Float64Rhs) -> None: ...
+    def p_new_view_with_indices(
+        self,
+        indices: ArrayViewIndices,
+    ) -> typing.Self: ...
+    def p_column(self) -> Column: ...
+    def get(self, index: int) -> float: ...
+    def to_str(self) -> str: ...
+    def __getitem__(self, key: GetItemKey) -> Float64: ...
+    def __setitem__(
+        self,
+        key: GetItemKey,
+        value: Float64Rhs,
+    ) -> None: ...
+    def __len__(self) -> int: ...
+    def __add__(self, other: Float64ArithRhs) -> npt.NDArray[np.float64]: ...
+    def __iadd__(self, other: Float64ArithRhs) -> Float64: ...
+    def __sub__(self, other: Float64ArithRhs) -> npt.NDArray[np.float64]: ...
+    def __isub__(self, other: Float64ArithRhs) -> Float64: ...
+    def __mul__(self, other: Float64ArithRhs) -> npt.NDArray[np.float64]: ...
+    def __imul__(self, other: Float64ArithRhs) -> Float64: ...
+    def __truediv__(
+        self, other: Float64ArithRhs
+    ) -> npt.NDArray[np.float64]: ...
+    def __itruediv__(self, other: Float64ArithRhs) -> Float64: ...
+    def __floordiv__(
+        self, other: Float64ArithRhs
+    ) -> npt.NDArray[np.float64]: ...
+    def __ifloordiv__(self, other: Float64ArithRhs) -> Float64: ...
+    def __mod__(self, other: Float64ArithRhs) -> npt.NDArray[np.float64]: ...
+    def __imod__(self, other: Float64ArithRhs) -> Float64: ...
+    def __pow__(
+        self, other: Float64Rhs | Int32
+    ) -> npt.NDArray[np.float64]: ...
+    def __ipow__(self, other: Float64Rhs | Int32) -> Float64: ...
+    def __lt__(self, other: Float64ArithRhs) -> npt.NDArray[np.bool_]: ...
+    def __le__(self, other: Float64ArithRhs) -> npt.NDArray[np.bool_]: ...
+    def __gt__(self, other: Float64ArithRhs) -> npt.NDArray[np.bool_]: ...
+    def __ge__(self, other: Float64ArithRhs) -> npt.NDArray[np.bool_]: ...
+    def __eq__(self, other: Float64ArithRhs) -> npt.NDArray[np.bool_]: ...  # type: ignore
+    def __ne__(self, other: Float64ArithRhs) -> npt.NDArray[np.bool_]: ...  # type: ignore
+    def clamp(self, min: float, max: float) -> npt.NDArray[np.float64]: ...
+    def clamp_in_place(self, min: float, max: float) -> None: ...
+    def sum(self) -> float: ...
+    def mean(self) -> float: ...
+    def min(self) -> float: ...
+    def max(self) -> float: ...
+    def argmin(self) -> int: ...
+    def argmax(self) -> int: ...
+    def any(self) -> bool: ...
+    def all(self) -> bool: ...
+    def __neg__(self) -> npt.NDArray[np.float64]: ...
+    def neg_in_place(self) -> None: ...
+    def __abs__(self) -> npt.NDArray[np.float64]: ...
+    def abs_in_place(self) -> None: ...
+    def __round__(
+        self,
+        ndigits: int | None = None,
+    ) -> npt.NDArray[np.float64]: ...
+    def round_in_place(self, ndigits: int | None = None) -> None: ...
+    def sqrt(self) -> npt.NDArray[np.float64]: ...
+    def sqrt_in_place(self) -> None: ...
+    def exp(self) -> npt.NDArray[np.float64]: ...
+    def exp_in_place(self) -> None: ...
+    def log(self) -> npt.NDArray[np.float64]: ...
+    def log_in_place(self) -> None: ...
+    def sin(self) -> npt.NDArray[np.float64]: ...
+    def sin_in_place(self) -> None: ...
+    def cos(self) -> npt.NDArray[np.float64]: ...
+    def cos_in_place(self) -> None: ...
+    def atan2(self, other: Float64Rhs) -> npt.NDArray[np.float64]: ...
+    def atan2_in_place(self, other: Float64Rhs) -> None: ...
+    def floor(self) -> npt.NDArray[np.float64]: ...
+    def floor_in_place(self) -> None: ...
+    def ceil(self) -> npt.NDArray[np.float64]: ...
+    def ceil_in_place(self) -> None: ...
+
+Int8Rhs: typing.TypeAlias = (
+    int | Int8 | npt.NDArray[np.int8] | list[int] | tuple[int, ...]
+)
+Int8ArithRhs: typing.TypeAlias = Int8Rhs | Bool
+Int8PowRhs: typing.TypeAlias = (
+    int
+    | Int8
+    | npt.NDArray[np.int8]
+    | npt.NDArray[np.uint32]
+    | list[int]
+    | tuple[int, ...]
+)
+
+class Int8:
+    @staticmethod
+    def p_default_value() -> int: ...
+    @staticmethod
+    def p_from_value(value: int, num: int) -> Int8: ...
+    @staticmethod
+    def p_from_numpy(array: npt.NDArray[np.int8]) -> Int8: ...
+    @staticmethod
+    def p_from_indices(
+        indices: ArrayViewIndices,
+        default: int,
+        overflow: Overflow | None = None,
+    ) -> Int8: ...
+    def numpy(self) -> npt.NDArray[np.int8]: ...
+    def fill(self, values: Int8Rhs) -> None: ...
+    def p_new_view_with_indices(
+        self,
+        indices: ArrayViewIndices,
+    ) -> typing.Self: ...
+    def p_column(self) -> Column: ...
+    def get(self, index: int) -> int: ...
+    def to_str(self) -> str: ...
+    def __getitem__(self, key: GetItemKey) -> Int8: ...
+    def __setitem__(
+        self,
+        key: GetItemKey,
+        value: Int8Rhs,
+    ) -> None: ...
+    def __len__(self) -> int: ...
+    def __add__(self, other: Int8ArithRhs) -> npt.NDArray[np.int8]: ...
+    def __iadd__(self, other: Int8ArithRhs) -> Int8: ...
+    def __sub__(self, other: Int8ArithRhs) -> npt.NDArray[np.int8]: ...
+    def __isub__(self, other: Int8ArithRhs) -> Int8: ...
+    def __mul__(self, other: Int8ArithRhs) -> npt.NDArray[np.int8]: ...
+    def __imul__(self, other: Int8ArithRhs) -> Int8: ...
+    def __truediv__(self, other: Int8ArithRhs) -> npt.NDArray[np.int8]: ...
+    def __itruediv__(self, other: Int8ArithRhs) -> Int8: ...
+    def __floordiv__(self, other: Int8ArithRhs) -> npt.NDArray[np.int8]: ...
+    def __ifloordiv__(self, other: Int8ArithRhs) -> Int8: ...
+    def __mod__(self, other: Int8ArithRhs) -> npt.NDArray[np.int8]: ...
+    def __imod__(self, other: Int8ArithRhs) -> Int8: ...
+    def __pow__(self, other: Int8PowRhs) -> npt.NDArray[np.int8]: ...
+    def __ipow__(self, other: Int8PowRhs) -> Int8: ...
+    def __lt__(self, other: Int8ArithRhs) -> npt.NDArray[np.bool_]: ...
+    def __le__(self, other: Int8ArithRhs) -> npt.NDArray[np.bool_]: ...
+    def __gt__(self, other: Int8ArithRhs) -> npt.NDArray[np.bool_]: ...
+    def __ge__(self, other: Int8ArithRhs) -> npt.NDArray[np.bool_]: ...
+    def __eq__(self, other: Int8ArithRhs) -> npt.NDArray[np.bool_]: ...  # type: ignore
+    def __ne__(self, other: Int8ArithRhs) -> npt.NDArray[np.bool_]: ...  # type: ignore
+    def clamp(self, min: int, max: int) -> npt.NDArray[np.int8]: ...
+    def clamp_in_place(self, min: int, max: int) -> None: ...
+    def sum(self) -> int: ...
+    def mean(self) -> float: ...
+    def min(self) -> int: ...
+    def max(self) -> int: ...
+    def argmin(self) -> int: ...
+    def argmax(self) -> int: ...
+    def any(self) -> bool: ...
+    def all(self) -> bool: ...
+    def __and__(self, other: Int8Rhs) -> npt.NDArray[np.int8]: ...
+    def __iand__(self, other: Int8Rhs) -> Int8: ...
+    def __or__(self, other: Int8Rhs) -> npt.NDArray[np.int8]: ...
+    def __ior__(self, other: Int8Rhs) -> Int8: ...
+    def __xor__(self, other: Int8Rhs) -> npt.NDArray[np.int8]: ...
+    def __ixor__(self, other: Int8Rhs) -> Int8: ...
+    def __lshift__(self, other: Int8Rhs) -> npt.NDArray[np.int8]: ...
+    def __ilshift__(self, other: Int8Rhs) -> Int8: ...
+    def __rshift__(self, other: Int8Rhs) -> npt.NDArray[np.int8]: ...
+    def __irshift__(self, other: Int8Rhs) -> Int8: ...
+    def __invert__(self) -> npt.NDArray[np.int8]: ...
+    def invert_in_place(self) -> None: ...
+    def set_overflow(self, mode: Overflow) -> None: ...
+    def overflow(self) -> Overflow: ...
+    def __neg__(self) -> npt.NDArray[np.int8]: ...
+    def neg_in_place(self) -> None: ...
+    def __abs__(self) -> npt.NDArray[np.int8]: ...
+    def abs_in_place(self) -> None: ...
+
+Int16Rhs: typing.TypeAlias = (
+    int | Int16 | npt.NDArray[np.int16] | list[int] | tuple[int, ...]
+)
+Int16ArithRhs: typing.TypeAlias = Int16Rhs | Bool
+Int16PowRhs: typing.TypeAlias = (
+    int
+    | Int16
+    | npt.NDArray[np.int16]
+    | npt.NDArray[np.uint32]
+    | list[int]
+    | tuple[int, ...]
+)
+
+class Int16:
+    @staticmethod
+    def p_default_value() -> int: ...
+    @staticmethod
+    def p_from_value(value: int, num: int) -> Int16: ...
+    @staticmethod
+    def p_from_numpy(array: npt.NDArray[np.int16]) -> Int16: ...
+    @staticmethod
+    def p_from_indices(
+        indices: ArrayViewIndices,
+        default: int,
+        overflow: Overflow | None = None,
+    ) -> Int16: ...
+    def numpy(self) -> npt.NDArray[np.int16]: ...
+    def fill(self, values: Int16Rhs) -> None: ...
+    def p_new_view_with_indices(
+        self,
+        indices: ArrayViewIndices,
+    ) -> typing.Self: ...
+    def p_column(self) -> Column: ...
+    def get(self, index: int) -> int: ...
+    def to_str(self) -> str: ...
+    def __getitem__(self, key: GetItemKey) -> Int16: ...
+    def __setitem__(
+        self,
+        key: GetItemKey,
+        value: Int16Rhs,
+    ) -> None: ...
+    def __len__(self) -> int: ...
+    def __add__(self, other: Int16ArithRhs) -> npt.NDArray[np.int16]: ...
+    def __iadd__(self, other: Int16ArithRhs) -> Int16: ...
+    def __sub__(self, other: Int16ArithRhs) -> npt.NDArray[np.int16]: ...
+    def __isub__(self, other: Int16ArithRhs) -> Int16: ...
+    def __mul__(self, other: Int16ArithRhs) -> npt.NDArray[np.int16]: ...
+    def __imul__(self, other: Int16ArithRhs) -> Int16: ...
+    def __truediv__(self, other: Int16ArithRhs) -> npt.NDArray[np.int16]: ...
+    def __itruediv__(self, other: Int16ArithRhs) -> Int16: ...
+    def __floordiv__(self, other: Int16ArithRhs) -> npt.NDArray[np.int16]: ...
+    def __ifloordiv__(self, other: Int16ArithRhs) -> Int16: ...
+    def __mod__(self, other: Int16ArithRhs) -> npt.NDArray[np.int16]: ...
+    def __imod__(self, other: Int16ArithRhs) -> Int16: ...
+    def __pow__(self, other: Int16PowRhs) -> npt.NDArray[np.int16]: ...
+    def __ipow__(self, other: Int16PowRhs) -> Int16: ...
+    def __lt__(self, other: Int16ArithRhs) -> npt.NDArray[np.bool_]: ...
+    def __le__(self, other: Int16ArithRhs) -> npt.NDArray[np.bool_]: ...
+    def __gt__(self, other: Int16ArithRhs) -> npt.NDArray[np.bool_]: ...
+    def __ge__(self, other: Int16ArithRhs) -> npt.NDArray[np.bool_]: ...
+    def __eq__(self, other: Int16ArithRhs) -> npt.NDArray[np.bool_]: ...  # type: ignore
+    def __ne__(self, other: Int16ArithRhs) -> npt.NDArray[np.bool_]: ...  # type: ignore
+    def clamp(self, min: int, max: int) -> npt.NDArray[np.int16]: ...
+    def clamp_in_place(self, min: int, max: int) -> None: ...
+    def sum(self) -> int: ...
+    def mean(self) -> float: ...
+    def min(self) -> int: ...
+    def max(self) -> int: ...
+    def argmin(self) -> int: ...
+    def argmax(self) -> int: ...
+    def any(self) -> bool: ...
+    def all(self) -> bool: ...
+    def __and__(self, other: Int16Rhs) -> npt.NDArray[np.int16]: ...
+    def __iand__(self, other: Int16Rhs) -> Int16: ...
+    def __or__(self, other: Int16Rhs) -> npt.NDArray[np.int16]: ...
+    def __ior__(self, other: Int16Rhs) -> Int16: ...
+    def __xor__(self, other: Int16Rhs) -> npt.NDArray[np.int16]: ...
+    def __ixor__(self, other: Int16Rhs) -> Int16: ...
+    def __lshift__(self, other: Int16Rhs) -> npt.NDArray[np.int16]: ...
+    def __ilshift__(self, other: Int16Rhs) -> Int16: ...
+    def __rshift__(self, other: Int16Rhs) -> npt.NDArray[np.int16]: ...
+    def __irshift__(self, other: Int16Rhs) -> Int16: ...
+    def __invert__(self) -> npt.NDArray[np.int16]: ...
+    def invert_in_place(self) -> None: ...
+    def set_overflow(self, mode: Overflow) -> None: ...
+    def overflow(self) -> Overflow: ...
+    def __neg__(self) -> npt.NDArray[np.int16]: ...
+    def neg_in_place(self) -> None: ...
+    def __abs__(self) -> npt.NDArray[np.int16]: ...
+    def abs_in_place(self) -> None: ...
 
 Int32Rhs: typing.TypeAlias = (
     int | Int32 | npt.NDArray[np.int32] | list[int] | tuple[int, ...]
 )
+Int32ArithRhs: typing.TypeAlias = Int32Rhs | Bool
 Int32PowRhs: typing.TypeAlias = (
-    int | Int32 | npt.NDArray[np.uint32] | list[int] | tuple[int, ...]
+    int
+    | Int32
+    | npt.NDArray[np.int32]
+    | npt.NDArray[np.uint32]
+    | list[int]
+    | tuple[int, ...]
 )
 
 class Int32:
@@ -115,46 +542,389 @@ class Int32:
     @staticmethod
     def p_from_numpy(array: npt.NDArray[np.int32]) -> Int32: ...
     @staticmethod
-    def p_from_indices(indices: ArrayViewIndices, default: int) -> Int32: ...
+    def p_from_indices(
+        indices: ArrayViewIndices,
+        default: int,
+        overflow: Overflow | None = None,
+    ) -> Int32: ...
     def numpy(self) -> npt.NDArray[np.int32]: ...
     def fill(self, values: Int32Rhs) -> None: ...
     def p_new_view_with_indices(
         self,
         indices: ArrayViewIndices,
     ) -> typing.Self: ...
+    def p_column(self) -> Column: ...
     def get(self, index: int) -> int: ...
     def to_str(self) -> str: ...
-    def __getitem__(self, key: npt.NDArray[np.bool_]) -> Int32: ...
+    def __getitem__(self, key: GetItemKey) -> Int32: ...
     def __setitem__(
         self,
-        key: npt.NDArray[np.bool_],
+        key: GetItemKey,
         value: Int32Rhs,
     ) -> None: ...
     def __len__(self) -> int: ...
-    def __add__(self, other: Int32Rhs) -> npt.NDArray[np.int32]: ...
-    def __iadd__(self, other: Int32Rhs) -> Int32: ...
-    def __sub__(self, other: Int32Rhs) -> npt.NDArray[np.int32]: ...
-    def __isub__(self, other: Int32Rhs) -> Int32: ...
-    def __mul__(self, other: Int32Rhs) -> npt.NDArray[np.int32]: ...
-    def __imul__(self, other: Int32Rhs) -> Int32: ...
-    def __truediv__(self, other: Int32Rhs) -> npt.NDArray[np.int32]: ...
-    def __itruediv__(self, other: Int32Rhs) -> Int32: ...
-    def __floordiv__(self, other: Int32Rhs) -> npt.NDArray[np.int32]: ...
-    def __ifloordiv__(self, other: Int32Rhs) -> Int32: ...
-    def __mod__(self, other: Int32Rhs) -> npt.NDArray[np.int32]: ...
-    def __imod__(self, other: Int32Rhs) -> Int32: ...
+    @typing.overload
+    def __add__(self, other: Int32ArithRhs) -> npt.NDArray[np.int32]: ...
+    @typing.overload
+    def __add__(self, other: UInt32) -> npt.NDArray[np.int64]: ...
+    @typing.overload
+    def __add__(self, other: Float32 | Float64) -> npt.NDArray[np.float64]: ...
+    def __iadd__(self, other: Int32ArithRhs | UInt32) -> Int32: ...
+    @typing.overload
+    def __sub__(self, other: Int32ArithRhs) -> npt.NDArray[np.int32]: ...
+    @typing.overload
+    def __sub__(self, other: UInt32) -> npt.NDArray[np.int64]: ...
+    @typing.overload
+    def __sub__(self, other: Float32 | Float64) -> npt.NDArray[np.float64]: ...
+    def __isub__(self, other: Int32ArithRhs | UInt32) -> Int32: ...
+    @typing.overload
+    def __mul__(self, other: Int32ArithRhs) -> npt.NDArray[np.int32]: ...
+    @typing.overload
+    def __mul__(self, other: UInt32) -> npt.NDArray[np.int64]: ...
+    @typing.overload
+    def __mul__(self, other: Float32 | Float64) -> npt.NDArray[np.float64]: ...
+    def __imul__(self, other: Int32ArithRhs | UInt32) -> Int32: ...
+    @typing.overload
+    def __truediv__(self, other: Int32ArithRhs) -> npt.NDArray[np.int32]: ...
+    @typing.overload
+    def __truediv__(self, other: UInt32) -> npt.NDArray[np.int64]: ...
+    @typing.overload
+    def __truediv__(
+        self, other: Float32 | Float64
+    ) -> npt.NDArray[np.float64]: ...
+    def __itruediv__(self, other: Int32ArithRhs | UInt32) -> Int32: ...
+    @typing.overload
+    def __floordiv__(self, other: Int32ArithRhs) -> npt.NDArray[np.int32]: ...
+    @typing.overload
+    def __floordiv__(self, other: UInt32) -> npt.NDArray[np.int64]: ...
+    @typing.overload
+    def __floordiv__(
+        self, other: Float32 | Float64
+    ) -> npt.NDArray[np.float64]: ...
+    def __ifloordiv__(self, other: Int32ArithRhs | UInt32) -> Int32: ...
+    @typing.overload
+    def __mod__(self, other: Int32ArithRhs) -> npt.NDArray[np.int32]: ...
+    @typing.overload
+    def __mod__(self, other: UInt32) -> npt.NDArray[np.int64]: ...
+    @typing.overload
+    def __mod__(self, other: Float32 | Float64) -> npt.NDArray[np.float64]: ...
+    def __imod__(self, other: Int32ArithRhs | UInt32) -> Int32: ...
     def __pow__(self, other: Int32PowRhs) -> npt.NDArray[np.int32]: ...
     def __ipow__(self, other: Int32PowRhs) -> Int32: ...
-    def __lt__(self, other: Int32Rhs) -> npt.NDArray[np.bool_]: ...
-    def __le__(self, other: Int32Rhs) -> npt.NDArray[np.bool_]: ...
-    def __gt__(self, other: Int32Rhs) -> npt.NDArray[np.bool_]: ...
-    def __ge__(self, other: Int32Rhs) -> npt.NDArray[np.bool_]: ...
-    def __eq__(self, other: Int32Rhs) -> npt.NDArray[np.bool_]: ...  # type: ignore
-    def __ne__(self, other: Int32Rhs) -> npt.NDArray[np.bool_]: ...  # type: ignore
+    def __lt__(
+        self, other: Int32ArithRhs | UInt32 | Float32 | Float64
+    ) -> npt.NDArray[np.bool_]: ...
+    def __le__(
+        self, other: Int32ArithRhs | UInt32 | Float32 | Float64
+    ) -> npt.NDArray[np.bool_]: ...
+    def __gt__(
+        self, other: Int32ArithRhs | UInt32 | Float32 | Float64
+    ) -> npt.NDArray[np.bool_]: ...
+    def __ge__(
+        self, other: Int32ArithRhs | UInt32 | Float32 | Float64
+    ) -> npt.NDArray[np.bool_]: ...
+    def __eq__(self, other: Int32ArithRhs | UInt32 | Float32 | Float64) -> npt.NDArray[np.bool_]: ...  # type: ignore
+    def __ne__(self, other: Int32ArithRhs | UInt32 | Float32 | Float64) -> npt.NDArray[np.bool_]: ...  # type: ignore
+    def clamp(self, min: int, max: int) -> npt.NDArray[np.int32]: ...
+    def clamp_in_place(self, min: int, max: int) -> None: ...
+    def sum(self) -> int: ...
+    def mean(self) -> float: ...
+    def min(self) -> int: ...
+    def max(self) -> int: ...
+    def argmin(self) -> int: ...
+    def argmax(self) -> int: ...
+    def any(self) -> bool: ...
+    def all(self) -> bool: ...
+    def __and__(self, other: Int32Rhs) -> npt.NDArray[np.int32]: ...
+    def __iand__(self, other: Int32Rhs) -> Int32: ...
+    def __or__(self, other: Int32Rhs) -> npt.NDArray[np.int32]: ...
+    def __ior__(self, other: Int32Rhs) -> Int32: ...
+    def __xor__(self, other: Int32Rhs) -> npt.NDArray[np.int32]: ...
+    def __ixor__(self, other: Int32Rhs) -> Int32: ...
+    def __lshift__(self, other: Int32Rhs) -> npt.NDArray[np.int32]: ...
+    def __ilshift__(self, other: Int32Rhs) -> Int32: ...
+    def __rshift__(self, other: Int32Rhs) -> npt.NDArray[np.int32]: ...
+    def __irshift__(self, other: Int32Rhs) -> Int32: ...
+    def __invert__(self) -> npt.NDArray[np.int32]: ...
+    def invert_in_place(self) -> None: ...
+    def set_overflow(self, mode: Overflow) -> None: ...
+    def overflow(self) -> Overflow: ...
+    def __neg__(self) -> npt.NDArray[np.int32]: ...
+    def neg_in_place(self) -> None: ...
+    def __abs__(self) -> npt.NDArray[np.int32]: ...
+    def abs_in_place(self) -> None: ...
+
+Int64Rhs: typing.TypeAlias = (
+    int | Int64 | npt.NDArray[np.int64] | list[int] | tuple[int, ...]
+)
+Int64ArithRhs: typing.TypeAlias = Int64Rhs | Bool
+Int64PowRhs: typing.TypeAlias = (
+    int
+    | Int64
+    | npt.NDArray[np.int64]
+    | npt.NDArray[np.uint32]
+    | list[int]
+    | tuple[int, ...]
+)
+
+class Int64:
+    @staticmethod
+    def p_default_value() -> int: ...
+    @staticmethod
+    def p_from_value(value: int, num: int) -> Int64: ...
+    @staticmethod
+    def p_from_numpy(array: npt.NDArray[np.int64]) -> Int64: ...
+    @staticmethod
+    def p_from_indices(
+        indices: ArrayViewIndices,
+        default: int,
+        overflow: Overflow | None = None,
+    ) -> Int64: ...
+    def numpy(self) -> npt.NDArray[np.int64]: ...
+    def fill(self, values: Int64Rhs) -> None: ...
+    def p_new_view_with_indices(
+        self,
+        indices: ArrayViewIndices,
+    ) -> typing.Self: ...
+    def p_column(self) -> Column: ...
+    def get(self, index: int) -> int: ...
+    def to_str(self) -> str: ...
+    def __getitem__(self, key: GetItemKey) -> Int64: ...
+    def __setitem__(
+        self,
+        key: GetItemKey,
+        value: Int64Rhs,
+    ) -> None: ...
+    def __len__(self) -> int: ...
+    def __add__(self, other: Int64ArithRhs) -> npt.NDArray[np.int64]: ...
+    def __iadd__(self, other: Int64ArithRhs) -> Int64: ...
+    def __sub__(self, other: Int64ArithRhs) -> npt.NDArray[np.int64]: ...
+    def __isub__(self, other: Int64ArithRhs) -> Int64: ...
+    def __mul__(self, other: Int64ArithRhs) -> npt.NDArray[np.int64]: ...
+    def __imul__(self, other: Int64ArithRhs) -> Int64: ...
+    def __truediv__(self, other: Int64ArithRhs) -> npt.NDArray[np.int64]: ...
+    def __itruediv__(self, other: Int64ArithRhs) -> Int64: ...
+    def __floordiv__(self, other: Int64ArithRhs) -> npt.NDArray[np.int64]: ...
+    def __ifloordiv__(self, other: Int64ArithRhs) -> Int64: ...
+    def __mod__(self, other: Int64ArithRhs) -> npt.NDArray[np.int64]: ...
+    def __imod__(self, other: Int64ArithRhs) -> Int64: ...
+    def __pow__(self, other: Int64PowRhs) -> npt.NDArray[np.int64]: ...
+    def __ipow__(self, other: Int64PowRhs) -> Int64: ...
+    def __lt__(self, other: Int64ArithRhs) -> npt.NDArray[np.bool_]: ...
+    def __le__(self, other: Int64ArithRhs) -> npt.NDArray[np.bool_]: ...
+    def __gt__(self, other: Int64ArithRhs) -> npt.NDArray[np.bool_]: ...
+    def __ge__(self, other: Int64ArithRhs) -> npt.NDArray[np.bool_]: ...
+    def __eq__(self, other: Int64ArithRhs) -> npt.NDArray[np.bool_]: ...  # type: ignore
+    def __ne__(self, other: Int64ArithRhs) -> npt.NDArray[np.bool_]: ...  # type: ignore
+    def clamp(self, min: int, max: int) -> npt.NDArray[np.int64]: ...
+    def clamp_in_place(self, min: int, max: int) -> None: ...
+    def sum(self) -> int: ...
+    def mean(self) -> float: ...
+    def min(self) -> int: ...
+    def max(self) -> int: ...
+    def argmin(self) -> int: ...
+    def argmax(self) -> int: ...
+    def any(self) -> bool: ...
+    def all(self) -> bool: ...
+    def __and__(self, other: Int64Rhs) -> npt.NDArray[np.int64]: ...
+    def __iand__(self, other: Int64Rhs) -> Int64: ...
+    def __or__(self, other: Int64Rhs) -> npt.NDArray[np.int64]: ...
+    def __ior__(self, other: Int64Rhs) -> Int64: ...
+    def __xor__(self, other: Int64Rhs) -> npt.NDArray[np.int64]: ...
+    def __ixor__(self, other: Int64Rhs) -> Int64: ...
+    def __lshift__(self, other: Int64Rhs) -> npt.NDArray[np.int64]: ...
+    def __ilshift__(self, other: Int64Rhs) -> Int64: ...
+    def __rshift__(self, other: Int64Rhs) -> npt.NDArray[np.int64]: ...
+    def __irshift__(self, other: Int64Rhs) -> Int64: ...
+    def __invert__(self) -> npt.NDArray[np.int64]: ...
+    def invert_in_place(self) -> None: ...
+    def set_overflow(self, mode: Overflow) -> None: ...
+    def overflow(self) -> Overflow: ...
+    def __neg__(self) -> npt.NDArray[np.int64]: ...
+    def neg_in_place(self) -> None: ...
+    def __abs__(self) -> npt.NDArray[np.int64]: ...
+    def abs_in_place(self) -> None: ...
+
+UInt8Rhs: typing.TypeAlias = (
+    int | UInt8 | npt.NDArray[np.uint8] | list[int] | tuple[int, ...]
+)
+UInt8ArithRhs: typing.TypeAlias = UInt8Rhs | Bool
+UInt8PowRhs: typing.TypeAlias = (
+    int
+    | UInt8
+    | npt.NDArray[np.uint8]
+    | npt.NDArray[np.uint32]
+    | list[int]
+    | tuple[int, ...]
+)
+
+class UInt8:
+    @staticmethod
+    def p_default_value() -> int: ...
+    @staticmethod
+    def p_from_value(value: int, num: int) -> UInt8: ...
+    @staticmethod
+    def p_from_numpy(array: npt.NDArray[np.uint8]) -> UInt8: ...
+    @staticmethod
+    def p_from_indices(
+        indices: ArrayViewIndices,
+        default: int,
+        overflow: Overflow | None = None,
+    ) -> UInt8: ...
+    def numpy(self) -> npt.NDArray[np.uint8]: ...
+    def fill(self, values: UInt8Rhs) -> None: ...
+    def p_new_view_with_indices(
+        self,
+        indices: ArrayViewIndices,
+    ) -> typing.Self: ...
+    def p_column(self) -> Column: ...
+    def get(self, index: int) -> int: ...
+    def to_str(self) -> str: ...
+    def __getitem__(self, key: GetItemKey) -> UInt8: ...
+    def __setitem__(
+        self,
+        key: GetItemKey,
+        value: UInt8Rhs,
+    ) -> None: ...
+    def __len__(self) -> int: ...
+    def __add__(self, other: UInt8ArithRhs) -> npt.NDArray[np.uint8]: ...
+    def __iadd__(self, other: UInt8ArithRhs) -> UInt8: ...
+    def __sub__(self, other: UInt8ArithRhs) -> npt.NDArray[np.uint8]: ...
+    def __isub__(self, other: UInt8ArithRhs) -> UInt8: ...
+    def __mul__(self, other: UInt8ArithRhs) -> npt.NDArray[np.uint8]: ...
+    def __imul__(self, other: UInt8ArithRhs) -> UInt8: ...
+    def __truediv__(self, other: UInt8ArithRhs) -> npt.NDArray[np.uint8]: ...
+    def __itruediv__(self, other: UInt8ArithRhs) -> UInt8: ...
+    def __floordiv__(self, other: UInt8ArithRhs) -> npt.NDArray[np.uint8]: ...
+    def __ifloordiv__(self, other: UInt8ArithRhs) -> UInt8: ...
+    def __mod__(self, other: UInt8ArithRhs) -> npt.NDArray[np.uint8]: ...
+    def __imod__(self, other: UInt8ArithRhs) -> UInt8: ...
+    def __pow__(self, other: UInt8PowRhs) -> npt.NDArray[np.uint8]: ...
+    def __ipow__(self, other: UInt8PowRhs) -> UInt8: ...
+    def __lt__(self, other: UInt8ArithRhs) -> npt.NDArray[np.bool_]: ...
+    def __le__(self, other: UInt8ArithRhs) -> npt.NDArray[np.bool_]: ...
+    def __gt__(self, other: UInt8ArithRhs) -> npt.NDArray[np.bool_]: ...
+    def __ge__(self, other: UInt8ArithRhs) -> npt.NDArray[np.bool_]: ...
+    def __eq__(self, other: UInt8ArithRhs) -> npt.NDArray[np.bool_]: ...  # type: ignore
+    def __ne__(self, other: UInt8ArithRhs) -> npt.NDArray[np.bool_]: ...  # type: ignore
+    def clamp(self, min: int, max: int) -> npt.NDArray[np.uint8]: ...
+    def clamp_in_place(self, min: int, max: int) -> None: ...
+    def sum(self) -> int: ...
+    def mean(self) -> float: ...
+    def min(self) -> int: ...
+    def max(self) -> int: ...
+    def argmin(self) -> int: ...
+    def argmax(self) -> int: ...
+    def any(self) -> bool: ...
+    def all(self) -> bool: ...
+    def __and__(self, other: UInt8Rhs) -> npt.NDArray[np.uint8]: ...
+    def __iand__(self, other: UInt8Rhs) -> UInt8: ...
+    def __or__(self, other: UInt8Rhs) -> npt.NDArray[np.uint8]: ...
+    def __ior__(self, other: UInt8Rhs) -> UInt8: ...
+    def __xor__(self, other: UInt8Rhs) -> npt.NDArray[np.uint8]: ...
+    def __ixor__(self, other: UInt8Rhs) -> UInt8: ...
+    def __lshift__(self, other: UInt8Rhs) -> npt.NDArray[np.uint8]: ...
+    def __ilshift__(self, other: UInt8Rhs) -> UInt8: ...
+    def __rshift__(self, other: UInt8Rhs) -> npt.NDArray[np.uint8]: ...
+    def __irshift__(self, other: UInt8Rhs) -> UInt8: ...
+    def __invert__(self) -> npt.NDArray[np.uint8]: ...
+    def invert_in_place(self) -> None: ...
+    def set_overflow(self, mode: Overflow) -> None: ...
+    def overflow(self) -> Overflow: ...
+
+UInt16Rhs: typing.TypeAlias = (
+    int | UInt16 | npt.NDArray[np.uint16] | list[int] | tuple[int, ...]
+)
+UInt16ArithRhs: typing.TypeAlias = UInt16Rhs | Bool
+UInt16PowRhs: typing.TypeAlias = (
+    int
+    | UInt16
+    | npt.NDArray[np.uint16]
+    | npt.NDArray[np.uint32]
+    | list[int]
+    | tuple[int, ...]
+)
+
+class UInt16:
+    @staticmethod
+    def p_default_value() -> int: ...
+    @staticmethod
+    def p_from_value(value: int, num: int) -> UInt16: ...
+    @staticmethod
+    def p_from_numpy(array: npt.NDArray[np.uint16]) -> UInt16: ...
+    @staticmethod
+    def p_from_indices(
+        indices: ArrayViewIndices,
+        default: int,
+        overflow: Overflow | None = None,
+    ) -> UInt16: ...
+    def numpy(self) -> npt.NDArray[np.uint16]: ...
+    def fill(self, values: UInt16Rhs) -> None: ...
+    def p_new_view_with_indices(
+        self,
+        indices: ArrayViewIndices,
+    ) -> typing.Self: ...
+    def p_column(self) -> Column: ...
+    def get(self, index: int) -> int: ...
+    def to_str(self) -> str: ...
+    def __getitem__(self, key: GetItemKey) -> UInt16: ...
+    def __setitem__(
+        self,
+        key: GetItemKey,
+        value: UInt16Rhs,
+    ) -> None: ...
+    def __len__(self) -> int: ...
+    def __add__(self, other: UInt16ArithRhs) -> npt.NDArray[np.uint16]: ...
+    def __iadd__(self, other: UInt16ArithRhs) -> UInt16: ...
+    def __sub__(self, other: UInt16ArithRhs) -> npt.NDArray[np.uint16]: ...
+    def __isub__(self, other: UInt16ArithRhs) -> UInt16: ...
+    def __mul__(self, other: UInt16ArithRhs) -> npt.NDArray[np.uint16]: ...
+    def __imul__(self, other: UInt16ArithRhs) -> UInt16: ...
+    def __truediv__(self, other: UInt16ArithRhs) -> npt.NDArray[np.uint16]: ...
+    def __itruediv__(self, other: UInt16ArithRhs) -> UInt16: ...
+    def __floordiv__(
+        self, other: UInt16ArithRhs
+    ) -> npt.NDArray[np.uint16]: ...
+    def __ifloordiv__(self, other: UInt16ArithRhs) -> UInt16: ...
+    def __mod__(self, other: UInt16ArithRhs) -> npt.NDArray[np.uint16]: ...
+    def __imod__(self, other: UInt16ArithRhs) -> UInt16: ...
+    def __pow__(self, other: UInt16PowRhs) -> npt.NDArray[np.uint16]: ...
+    def __ipow__(self, other: UInt16PowRhs) -> UInt16: ...
+    def __lt__(self, other: UInt16ArithRhs) -> npt.NDArray[np.bool_]: ...
+    def __le__(self, other: UInt16ArithRhs) -> npt.NDArray[np.bool_]: ...
+    def __gt__(self, other: UInt16ArithRhs) -> npt.NDArray[np.bool_]: ...
+    def __ge__(self, other: UInt16ArithRhs) -> npt.NDArray[np.bool_]: ...
+    def __eq__(self, other: UInt16ArithRhs) -> npt.NDArray[np.bool_]: ...  # type: ignore
+    def __ne__(self, other: UInt16ArithRhs) -> npt.NDArray[np.bool_]: ...  # type: ignore
+    def clamp(self, min: int, max: int) -> npt.NDArray[np.uint16]: ...
+    def clamp_in_place(self, min: int, max: int) -> None: ...
+    def sum(self) -> int: ...
+    def mean(self) -> float: ...
+    def min(self) -> int: ...
+    def max(self) -> int: ...
+    def argmin(self) -> int: ...
+    def argmax(self) -> int: ...
+    def any(self) -> bool: ...
+    def all(self) -> bool: ...
+    def __and__(self, other: UInt16Rhs) -> npt.NDArray[np.uint16]: ...
+    def __iand__(self, other: UInt16Rhs) -> UInt16: ...
+    def __or__(self, other: UInt16Rhs) -> npt.NDArray[np.uint16]: ...
+    def __ior__(self, other: UInt16Rhs) -> UInt16: ...
+    def __xor__(self, other: UInt16Rhs) -> npt.NDArray[np.uint16]: ...
+    def __ixor__(self, other: UInt16Rhs) -> UInt16: ...
+    def __lshift__(self, other: UInt16Rhs) -> npt.NDArray[np.uint16]: ...
+    def __ilshift__(self, other: UInt16Rhs) -> UInt16: ...
+    def __rshift__(self, other: UInt16Rhs) -> npt.NDArray[np.uint16]: ...
+    def __irshift__(self, other: UInt16Rhs) -> UInt16: ...
+    def __invert__(self) -> npt.NDArray[np.uint16]: ...
+    def invert_in_place(self) -> None: ...
+    def set_overflow(self, mode: Overflow) -> None: ...
+    def overflow(self) -> Overflow: ...
 
 UInt32Rhs: typing.TypeAlias = (
     int | UInt32 | npt.NDArray[np.uint32] | list[int] | tuple[int, ...]
 )
+UInt32ArithRhs: typing.TypeAlias = UInt32Rhs | Bool
 
 class UInt32:
     @staticmethod
@@ -164,42 +934,204 @@ class UInt32:
     @staticmethod
     def p_from_numpy(array: npt.NDArray[np.uint32]) -> UInt32: ...
     @staticmethod
-    def p_from_indices(indices: ArrayViewIndices, default: int) -> UInt32: ...
+    def p_from_indices(
+        indices: ArrayViewIndices,
+        default: int,
+        overflow: Overflow | None = None,
+    ) -> UInt32: ...
     def numpy(self) -> npt.NDArray[np.uint32]: ...
     def fill(self, values: UInt32Rhs) -> None: ...
     def p_new_view_with_indices(
         self,
         indices: ArrayViewIndices,
     ) -> typing.Self: ...
+    def p_column(self) -> Column: ...
     def get(self, index: int) -> int: ...
     def to_str(self) -> str: ...
-    def __getitem__(self, key: npt.NDArray[np.bool_]) -> UInt32: ...
+    def __getitem__(self, key: GetItemKey) -> UInt32: ...
     def __setitem__(
         self,
-        key: npt.NDArray[np.bool_],
+        key: GetItemKey,
         value: UInt32Rhs,
     ) -> None: ...
     def __len__(self) -> int: ...
-    def __add__(self, other: UInt32Rhs) -> npt.NDArray[np.uint32]: ...
-    def __iadd__(self, other: UInt32Rhs) -> UInt32: ...
-    def __sub__(self, other: UInt32Rhs) -> npt.NDArray[np.uint32]: ...
-    def __isub__(self, other: UInt32Rhs) -> UInt32: ...
-    def __mul__(self, other: UInt32Rhs) -> npt.NDArray[np.uint32]: ...
-    def __imul__(self, other: UInt32Rhs) -> UInt32: ...
-    def __truediv__(self, other: UInt32Rhs) -> npt.NDArray[np.uint32]: ...
-    def __itruediv__(self, other: UInt32Rhs) -> UInt32: ...
-    def __floordiv__(self, other: UInt32Rhs) -> npt.NDArray[np.uint32]: ...
-    def __ifloordiv__(self, other: UInt32Rhs) -> UInt32: ...
-    def __mod__(self, other: UInt32Rhs) -> npt.NDArray[np.uint32]: ...
-    def __imod__(self, other: UInt32Rhs) -> UInt32: ...
+    @typing.overload
+    def __add__(self, other: UInt32ArithRhs) -> npt.NDArray[np.uint32]: ...
+    @typing.overload
+    def __add__(self, other: Int32) -> npt.NDArray[np.int64]: ...
+    @typing.overload
+    def __add__(self, other: Float32 | Float64) -> npt.NDArray[np.float64]: ...
+    def __iadd__(self, other: UInt32ArithRhs) -> UInt32: ...
+    @typing.overload
+    def __sub__(self, other: UInt32ArithRhs) -> npt.NDArray[np.uint32]: ...
+    @typing.overload
+    def __sub__(self, other: Int32) -> npt.NDArray[np.int64]: ...
+    @typing.overload
+    def __sub__(self, other: Float32 | Float64) -> npt.NDArray[np.float64]: ...
+    def __isub__(self, other: UInt32ArithRhs) -> UInt32: ...
+    @typing.overload
+    def __mul__(self, other: UInt32ArithRhs) -> npt.NDArray[np.uint32]: ...
+    @typing.overload
+    def __mul__(self, other: Int32) -> npt.NDArray[np.int64]: ...
+    @typing.overload
+    def __mul__(self, other: Float32 | Float64) -> npt.NDArray[np.float64]: ...
+    def __imul__(self, other: UInt32ArithRhs) -> UInt32: ...
+    @typing.overload
+    def __truediv__(self, other: UInt32ArithRhs) -> npt.NDArray[np.uint32]: ...
+    @typing.overload
+    def __truediv__(self, other: Int32) -> npt.NDArray[np.int64]: ...
+    @typing.overload
+    def __truediv__(
+        self, other: Float32 | Float64
+    ) -> npt.NDArray[np.float64]: ...
+    def __itruediv__(self, other: UInt32ArithRhs) -> UInt32: ...
+    @typing.overload
+    def __floordiv__(
+        self, other: UInt32ArithRhs
+    ) -> npt.NDArray[np.uint32]: ...
+    @typing.overload
+    def __floordiv__(self, other: Int32) -> npt.NDArray[np.int64]: ...
+    @typing.overload
+    def __floordiv__(
+        self, other: Float32 | Float64
+    ) -> npt.NDArray[np.float64]: ...
+    def __ifloordiv__(self, other: UInt32ArithRhs) -> UInt32: ...
+    @typing.overload
+    def __mod__(self, other: UInt32ArithRhs) -> npt.NDArray[np.uint32]: ...
+    @typing.overload
+    def __mod__(self, other: Int32) -> npt.NDArray[np.int64]: ...
+    @typing.overload
+    def __mod__(self, other: Float32 | Float64) -> npt.NDArray[np.float64]: ...
+    def __imod__(self, other: UInt32ArithRhs) -> UInt32: ...
     def __pow__(self, other: UInt32Rhs) -> npt.NDArray[np.uint32]: ...
     def __ipow__(self, other: UInt32Rhs) -> UInt32: ...
-    def __lt__(self, other: UInt32Rhs) -> npt.NDArray[np.bool_]: ...
-    def __le__(self, other: UInt32Rhs) -> npt.NDArray[np.bool_]: ...
-    def __gt__(self, other: UInt32Rhs) -> npt.NDArray[np.bool_]: ...
-    def __ge__(self, other: UInt32Rhs) -> npt.NDArray[np.bool_]: ...
-    def __eq__(self, other: UInt32Rhs) -> npt.NDArray[np.bool_]: ...  # type: ignore
-    def __ne__(self, other: UInt32Rhs) -> npt.NDArray[np.bool_]: ...  # type: ignore
+    def __lt__(
+        self, other: UInt32ArithRhs | Int32 | Float32 | Float64
+    ) -> npt.NDArray[np.bool_]: ...
+    def __le__(
+        self, other: UInt32ArithRhs | Int32 | Float32 | Float64
+    ) -> npt.NDArray[np.bool_]: ...
+    def __gt__(
+        self, other: UInt32ArithRhs | Int32 | Float32 | Float64
+    ) -> npt.NDArray[np.bool_]: ...
+    def __ge__(
+        self, other: UInt32ArithRhs | Int32 | Float32 | Float64
+    ) -> npt.NDArray[np.bool_]: ...
+    def __eq__(self, other: UInt32ArithRhs | Int32 | Float32 | Float64) -> npt.NDArray[np.bool_]: ...  # type: ignore
+    def __ne__(self, other: UInt32ArithRhs | Int32 | Float32 | Float64) -> npt.NDArray[np.bool_]: ...  # type: ignore
+    def clamp(self, min: int, max: int) -> npt.NDArray[np.uint32]: ...
+    def clamp_in_place(self, min: int, max: int) -> None: ...
+    def sum(self) -> int: ...
+    def mean(self) -> float: ...
+    def min(self) -> int: ...
+    def max(self) -> int: ...
+    def argmin(self) -> int: ...
+    def argmax(self) -> int: ...
+    def any(self) -> bool: ...
+    def all(self) -> bool: ...
+    def __and__(self, other: UInt32Rhs) -> npt.NDArray[np.uint32]: ...
+    def __iand__(self, other: UInt32Rhs) -> UInt32: ...
+    def __or__(self, other: UInt32Rhs) -> npt.NDArray[np.uint32]: ...
+    def __ior__(self, other: UInt32Rhs) -> UInt32: ...
+    def __xor__(self, other: UInt32Rhs) -> npt.NDArray[np.uint32]: ...
+    def __ixor__(self, other: UInt32Rhs) -> UInt32: ...
+    def __lshift__(self, other: UInt32Rhs) -> npt.NDArray[np.uint32]: ...
+    def __ilshift__(self, other: UInt32Rhs) -> UInt32: ...
+    def __rshift__(self, other: UInt32Rhs) -> npt.NDArray[np.uint32]: ...
+    def __irshift__(self, other: UInt32Rhs) -> UInt32: ...
+    def __invert__(self) -> npt.NDArray[np.uint32]: ...
+    def invert_in_place(self) -> None: ...
+    def set_overflow(self, mode: Overflow) -> None: ...
+    def overflow(self) -> Overflow: ...
+
+UInt64Rhs: typing.TypeAlias = (
+    int | UInt64 | npt.NDArray[np.uint64] | list[int] | tuple[int, ...]
+)
+UInt64ArithRhs: typing.TypeAlias = UInt64Rhs | Bool
+UInt64PowRhs: typing.TypeAlias = (
+    int
+    | UInt64
+    | npt.NDArray[np.uint64]
+    | npt.NDArray[np.uint32]
+    | list[int]
+    | tuple[int, ...]
+)
+
+class UInt64:
+    @staticmethod
+    def p_default_value() -> int: ...
+    @staticmethod
+    def p_from_value(value: int, num: int) -> UInt64: ...
+    @staticmethod
+    def p_from_numpy(array: npt.NDArray[np.uint64]) -> UInt64: ...
+    @staticmethod
+    def p_from_indices(
+        indices: ArrayViewIndices,
+        default: int,
+        overflow: Overflow | None = None,
+    ) -> UInt64: ...
+    def numpy(self) -> npt.NDArray[np.uint64]: ...
+    def fill(self, values: UInt64Rhs) -> None: ...
+    def p_new_view_with_indices(
+        self,
+        indices: ArrayViewIndices,
+    ) -> typing.Self: ...
+    def p_column(self) -> Column: ...
+    def get(self, index: int) -> int: ...
+    def to_str(self) -> str: ...
+    def __getitem__(self, key: GetItemKey) -> UInt64: ...
+    def __setitem__(
+        self,
+        key: GetItemKey,
+        value: UInt64Rhs,
+    ) -> None: ...
+    def __len__(self) -> int: ...
+    def __add__(self, other: UInt64ArithRhs) -> npt.NDArray[np.uint64]: ...
+    def __iadd__(self, other: UInt64ArithRhs) -> UInt64: ...
+    def __sub__(self, other: UInt64ArithRhs) -> npt.NDArray[np.uint64]: ...
+    def __isub__(self, other: UInt64ArithRhs) -> UInt64: ...
+    def __mul__(self, other: UInt64ArithRhs) -> npt.NDArray[np.uint64]: ...
+    def __imul__(self, other: UInt64ArithRhs) -> UInt64: ...
+    def __truediv__(self, other: UInt64ArithRhs) -> npt.NDArray[np.uint64]: ...
+    def __itruediv__(self, other: UInt64ArithRhs) -> UInt64: ...
+    def __floordiv__(
+        self, other: UInt64ArithRhs
+    ) -> npt.NDArray[np.uint64]: ...
+    def __ifloordiv__(self, other: UInt64ArithRhs) -> UInt64: ...
+    def __mod__(self, other: UInt64ArithRhs) -> npt.NDArray[np.uint64]: ...
+    def __imod__(self, other: UInt64ArithRhs) -> UInt64: ...
+    def __pow__(self, other: UInt64PowRhs) -> npt.NDArray[np.uint64]: ...
+    def __ipow__(self, other: UInt64PowRhs) -> UInt64: ...
+    def __lt__(self, other: UInt64ArithRhs) -> npt.NDArray[np.bool_]: ...
+    def __le__(self, other: UInt64ArithRhs) -> npt.NDArray[np.bool_]: ...
+    def __gt__(self, other: UInt64ArithRhs) -> npt.NDArray[np.bool_]: ...
+    def __ge__(self, other: UInt64ArithRhs) -> npt.NDArray[np.bool_]: ...
+    def __eq__(self, other: UInt64ArithRhs) -> npt.NDArray[np.bool_]: ...  # type: ignore
+    def __ne__(self, other: UInt64ArithRhs) -> npt.NDArray[np.bool_]: ...  # type: ignore
+    def clamp(self, min: int, max: int) -> npt.NDArray[np.uint64]: ...
+    def clamp_in_place(self, min: int, max: int) -> None: ...
+    def sum(self) -> int: ...
+    def mean(self) -> float: ...
+    def min(self) -> int: ...
+    def max(self) -> int: ...
+    def argmin(self) -> int: ...
+    def argmax(self) -> int: ...
+    def any(self) -> bool: ...
+    def all(self) -> bool: ...
+    def __and__(self, other: UInt64Rhs) -> npt.NDArray[np.uint64]: ...
+    def __iand__(self, other: UInt64Rhs) -> UInt64: ...
+    def __or__(self, other: UInt64Rhs) -> npt.NDArray[np.uint64]: ...
+    def __ior__(self, other: UInt64Rhs) -> UInt64: ...
+    def __xor__(self, other: UInt64Rhs) -> npt.NDArray[np.uint64]: ...
+    def __ixor__(self, other: UInt64Rhs) -> UInt64: ...
+    def __lshift__(self, other: UInt64Rhs) -> npt.NDArray[np.uint64]: ...
+    def __ilshift__(self, other: UInt64Rhs) -> UInt64: ...
+    def __rshift__(self, other: UInt64Rhs) -> npt.NDArray[np.uint64]: ...
+    def __irshift__(self, other: UInt64Rhs) -> UInt64: ...
+    def __invert__(self) -> npt.NDArray[np.uint64]: ...
+    def invert_in_place(self) -> None: ...
+    def set_overflow(self, mode: Overflow) -> None: ...
+    def overflow(self) -> Overflow: ...
 
 BoolRhs: typing.TypeAlias = (
     bool | Bool | npt.NDArray[np.bool_] | list[bool] | tuple[bool, ...]
@@ -220,17 +1152,35 @@ class Bool:
         self,
         indices: ArrayViewIndices,
     ) -> typing.Self: ...
+    def p_column(self) -> Column: ...
     def get(self, index: int) -> bool: ...
     def to_str(self) -> str: ...
-    def __getitem__(self, key: npt.NDArray[np.bool_]) -> Bool: ...
+    def __getitem__(self, key: GetItemKey) -> Bool: ...
     def __setitem__(
         self,
-        key: npt.NDArray[np.bool_],
+        key: GetItemKey,
         value: BoolRhs,
     ) -> None: ...
     def __len__(self) -> int: ...
     def __eq__(self, other: BoolRhs) -> npt.NDArray[np.bool_]: ...  # type: ignore
     def __ne__(self, other: BoolRhs) -> npt.NDArray[np.bool_]: ...  # type: ignore
+    def count_true(self) -> int: ...
+    def sum(self) -> int: ...
+    def mean(self) -> float: ...
+    def min(self) -> bool: ...
+    def max(self) -> bool: ...
+    def argmin(self) -> int: ...
+    def argmax(self) -> int: ...
+    def any(self) -> bool: ...
+    def all(self) -> bool: ...
+    def __and__(self, other: BoolRhs) -> npt.NDArray[np.bool_]: ...
+    def __iand__(self, other: BoolRhs) -> Bool: ...
+    def __or__(self, other: BoolRhs) -> npt.NDArray[np.bool_]: ...
+    def __ior__(self, other: BoolRhs) -> Bool: ...
+    def __xor__(self, other: BoolRhs) -> npt.NDArray[np.bool_]: ...
+    def __ixor__(self, other: BoolRhs) -> Bool: ...
+    def __invert__(self) -> npt.NDArray[np.bool_]: ...
+    def invert_in_place(self) -> None: ...
 
 class Duration:
     @staticmethod
@@ -272,6 +1222,40 @@ class Instant:
     def checked_add(self, duration: Duration) -> Instant: ...
     def checked_sub(self, duration: Duration) -> Instant: ...
 
+class PairChunks:
+    def __iter__(self) -> PairChunks: ...
+    def __next__(
+        self,
+    ) -> tuple[list[ArrayViewIndices], list[ArrayViewIndices]]: ...
+
+class KdTree:
+    def __init__(self, x: Float32, y: Float32) -> None: ...
+    def __len__(self) -> int: ...
+    def nearest(
+        self,
+        k: int,
+        indices: list[ArrayViewIndices],
+    ) -> tuple[
+        tuple[list[ArrayViewIndices], list[ArrayViewIndices]],
+        npt.NDArray[np.float32],
+    ]: ...
+    def nearest_to(
+        self,
+        x: Float32,
+        y: Float32,
+        k: int,
+        indices: list[ArrayViewIndices],
+    ) -> tuple[list[ArrayViewIndices], npt.NDArray[np.float32]]: ...
+
+class SpatialGrid:
+    def __init__(self, x: Float32, y: Float32, cell_size: float) -> None: ...
+    def __len__(self) -> int: ...
+    def pairs_within(
+        self,
+        radius: float,
+        indices: list[ArrayViewIndices],
+    ) -> tuple[list[ArrayViewIndices], list[ArrayViewIndices]]: ...
+
 class Time:
     @staticmethod
     def default() -> Time: ...
@@ -283,3 +1267,23 @@ class Time:
 def product_2(
     indices: list[ArrayViewIndices],
 ) -> tuple[list[ArrayViewIndices], list[ArrayViewIndices]]: ...
+def combinations_2(
+    indices: list[ArrayViewIndices],
+) -> tuple[list[ArrayViewIndices], list[ArrayViewIndices]]: ...
+def product_with(
+    indices1: list[ArrayViewIndices],
+    indices2: list[ArrayViewIndices],
+) -> tuple[list[ArrayViewIndices], list[ArrayViewIndices]]: ...
+def product_2_chunks(
+    indices: list[ArrayViewIndices],
+    chunk_size: int,
+) -> PairChunks: ...
+def combinations_2_chunks(
+    indices: list[ArrayViewIndices],
+    chunk_size: int,
+) -> PairChunks: ...
+def product_with_chunks(
+    indices1: list[ArrayViewIndices],
+    indices2: list[ArrayViewIndices],
+    chunk_size: int,
+) -> PairChunks: ...
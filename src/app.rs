@@ -1,62 +1,503 @@
-use crate::array_view_indices::MultipleArrayViewIndices;
+use crate::array_view_indices::{ArrayViewIndices, MultipleArrayViewIndices};
+use crate::column::Column;
 use crate::component_id::ComponentId;
-use crate::component_pool::ComponentPool;
+use crate::component_pool::{ComponentPool, Growth};
+use crate::entity_id::{self, EntityId};
 use crate::index::Index;
 use crate::map::Map;
 use crate::query::Query;
 use crate::query_id::QueryId;
+use crate::removed_reader_id::RemovedReaderId;
+use crate::set::Set;
+use crate::tick::{CurrentTick, Tick};
 use numpy::PyArray1;
+use pyo3::exceptions::{PyKeyError, PyRuntimeError, PyValueError};
 use pyo3::prelude::*;
+use std::sync::{Arc, RwLock};
+
+/// A mask of which matched entities hold an optional component, and
+/// the indices of the entities which do.
+type OptionalResult = (Py<PyArray1<bool>>, ArrayViewIndices);
+
+/// How a single query parameter reads from the Query it shares.
+struct QueryAccess {
+    // The position of the query in queries.
+    query_position: usize,
+    // The position of each fetched component in the components of
+    // the query.
+    component_positions: Vec<usize>,
+    optional_components: Vec<ComponentId>,
+    added_components: Vec<ComponentId>,
+    changed_components: Vec<ComponentId>,
+    // The tick at which the query was last run.
+    last_run: Tick,
+}
 
 #[pyclass]
 pub struct RustApp {
-    num_spawned_entities: Index,
-    // TODO: Queries should be cached by the COmponents they have
-    // so that if two of the same query appear in different systems
-    // it only needs to be performed once
+    // The generation of each entity index.
+    generations: Vec<u8>,
+    // Whether the entity at each index is alive.
+    alive: Vec<bool>,
+    // Indices of despawned entities, which can be reused.
+    free_entity_indices: Vec<Index>,
+    // Queries with the same set of components share a single Query,
+    // so that the matched entities are only found once.
     queries: Vec<Query>,
+    // Maps the sorted components and excluded components of a query
+    // to its position in queries.
+    query_positions: Map<(Vec<ComponentId>, Vec<ComponentId>), usize>,
+    // How each QueryId reads from queries.
+    query_accesses: Vec<QueryAccess>,
+    // For each component, the positions of the queries which include it.
+    component_queries: Map<ComponentId, Vec<usize>>,
+    // For each component, the positions of the queries which exclude it.
+    excluded_component_queries: Map<ComponentId, Vec<usize>>,
+    // For each RemovedReaderId, the entities which lost the component
+    // of the reader since it was last read.
+    removed_readers: Vec<Vec<EntityId>>,
+    // For each component, the readers of its removals.
+    component_removed_readers: Map<ComponentId, Vec<RemovedReaderId>>,
     pools: Map<ComponentId, ComponentPool>,
+    // The tick at which writes are currently recorded, shared with the
+    // columns of every pool.
+    tick: CurrentTick,
 }
 
 #[pymethods]
 impl RustApp {
-    fn spawn(&mut self, py: Python, components: Vec<ComponentId>, num: Index) -> Py<PyArray1<u32>> {
-        let entity_ids = (self.num_spawned_entities..self.num_spawned_entities + num).collect();
-        self.num_spawned_entities += num;
+    fn spawn(
+        &mut self,
+        py: Python,
+        components: Vec<ComponentId>,
+        num: Index,
+    ) -> PyResult<(Py<PyArray1<u32>>, Vec<ArrayViewIndices>)> {
+        check_unique(&components)?;
+        for component_id in components.iter() {
+            self.pools
+                .get_mut(component_id)
+                .ok_or_else(|| missing_pool(*component_id))?
+                .reserve(num as usize)?;
+        }
+        let num_reused = (num as usize).min(self.free_entity_indices.len());
+        let num_new = num as usize - num_reused;
+        if self.generations.len() + num_new > entity_id::MAX_ENTITIES {
+            return Err(PyRuntimeError::new_err(format!(
+                "cannot spawn more than {} entities",
+                entity_id::MAX_ENTITIES
+            )));
+        }
+
+        let num_spawned_entities = self.generations.len() as Index;
+        self.generations.resize(self.generations.len() + num_new, 0);
+        self.alive.resize(self.alive.len() + num_new, false);
+        let entity_ids: Vec<_> = self
+            .free_entity_indices
+            .drain(self.free_entity_indices.len() - num_reused..)
+            .chain(num_spawned_entities..num_spawned_entities + num_new as Index)
+            .map(|index| {
+                unsafe { *self.alive.get_unchecked_mut(index as usize) = true };
+                entity_id::new(index, unsafe {
+                    *self.generations.get_unchecked(index as usize)
+                })
+            })
+            .collect();
+
+        let mut indices = Vec::with_capacity(components.len());
+        for component_id in components.iter() {
+            let pool = self.pools.get_mut(component_id).unwrap();
+            indices.push(ArrayViewIndices(Arc::new(RwLock::new(
+                pool.add_entities(&entity_ids, self.tick.get())?,
+            ))));
+        }
+        for query_position in affected_queries(&self.component_queries, &components) {
+            unsafe { self.queries.get_unchecked_mut(query_position) }
+                .add_entities(&entity_ids, &self.pools);
+        }
+        Ok((PyArray1::from_vec(py, entity_ids).to_owned(), indices))
+    }
 
-        components.iter().for_each(|component_id| {
-            if let Some(pool) = self.pools.get_mut(component_id) {
-                pool.add_entities(&entity_ids);
+    fn despawn(&mut self, entity_ids: &PyArray1<u32>) -> PyResult<()> {
+        let entity_ids = entity_ids.to_vec()?;
+        self.check_alive(&entity_ids)?;
+        for (&component_id, pool) in self.pools.iter_mut() {
+            let removed = pool.remove_entities(&entity_ids)?;
+            record_removed(
+                &mut self.removed_readers,
+                &self.component_removed_readers,
+                component_id,
+                &removed,
+            );
+        }
+        for query in self.queries.iter_mut() {
+            query.remove_entities(&entity_ids);
+        }
+        for &entity_id in entity_ids.iter() {
+            let index = entity_id::index(entity_id);
+            let alive = unsafe { self.alive.get_unchecked_mut(index as usize) };
+            // The same entity can appear more than once in entity_ids.
+            if *alive {
+                *alive = false;
+                let generation = unsafe { self.generations.get_unchecked_mut(index as usize) };
+                // Reusing an index with the last generation would wrap the
+                // generation around and make old ids alive again, so the
+                // index is retired instead.
+                if *generation < u8::MAX {
+                    *generation += 1;
+                    self.free_entity_indices.push(index);
+                }
             }
-        });
-        PyArray1::from_vec(py, entity_ids).to_owned()
+        }
+        Ok(())
+    }
+
+    fn insert_components(
+        &mut self,
+        entity_ids: &PyArray1<u32>,
+        components: Vec<ComponentId>,
+    ) -> PyResult<Vec<ArrayViewIndices>> {
+        check_unique(&components)?;
+        let entity_ids = entity_ids.to_vec()?;
+        self.check_alive(&entity_ids)?;
+        let mut missing_entities = Vec::with_capacity(components.len());
+        for component_id in components.iter() {
+            let pool = self
+                .pools
+                .get_mut(component_id)
+                .ok_or_else(|| missing_pool(*component_id))?;
+            let missing = pool.missing_entities(&entity_ids);
+            pool.reserve(missing.len())?;
+            missing_entities.push(missing);
+        }
+
+        let mut indices = Vec::with_capacity(components.len());
+        for (component_id, missing) in components.iter().zip(missing_entities) {
+            let pool = self.pools.get_mut(component_id).unwrap();
+            pool.add_entities(&missing, self.tick.get())?;
+            indices.push(ArrayViewIndices(Arc::new(RwLock::new(
+                pool.entity_indices(&entity_ids),
+            ))));
+        }
+        for query_position in affected_queries(&self.excluded_component_queries, &components) {
+            unsafe { self.queries.get_unchecked_mut(query_position) }.remove_entities(&entity_ids);
+        }
+        for query_position in affected_queries(&self.component_queries, &components) {
+            unsafe { self.queries.get_unchecked_mut(query_position) }
+                .add_entities(&entity_ids, &self.pools);
+        }
+        Ok(indices)
+    }
+
+    fn remove_components(
+        &mut self,
+        entity_ids: &PyArray1<u32>,
+        components: Vec<ComponentId>,
+    ) -> PyResult<()> {
+        let entity_ids = entity_ids.to_vec()?;
+        self.check_alive(&entity_ids)?;
+        for &component_id in components.iter() {
+            let removed = self
+                .pools
+                .get_mut(&component_id)
+                .ok_or_else(|| missing_pool(component_id))?
+                .remove_entities(&entity_ids)?;
+            record_removed(
+                &mut self.removed_readers,
+                &self.component_removed_readers,
+                component_id,
+                &removed,
+            );
+        }
+        for query_position in affected_queries(&self.component_queries, &components) {
+            unsafe { self.queries.get_unchecked_mut(query_position) }.remove_entities(&entity_ids);
+        }
+        for query_position in affected_queries(&self.excluded_component_queries, &components) {
+            unsafe { self.queries.get_unchecked_mut(query_position) }
+                .add_entities(&entity_ids, &self.pools);
+        }
+        Ok(())
+    }
+
+    fn is_alive(&self, py: Python, entity_ids: &PyArray1<u32>) -> Py<PyArray1<bool>> {
+        let result = entity_ids
+            .readonly()
+            .as_array()
+            .iter()
+            .map(|&entity_id| self.entity_is_alive(entity_id))
+            .collect();
+        PyArray1::from_vec(py, result).into_py(py)
     }
 
     #[new]
     fn __new__(num_pools: usize, num_queries: usize) -> Self {
         Self {
-            num_spawned_entities: 0,
+            generations: Vec::new(),
+            alive: Vec::new(),
+            free_entity_indices: Vec::new(),
             pools: Map::with_capacity(num_pools),
             queries: Vec::with_capacity(num_queries),
+            query_positions: Map::with_capacity(num_queries),
+            query_accesses: Vec::with_capacity(num_queries),
+            component_queries: Map::with_capacity(num_pools),
+            excluded_component_queries: Map::with_capacity(num_pools),
+            removed_readers: Vec::new(),
+            component_removed_readers: Map::with_capacity(num_pools),
+            tick: CurrentTick::default(),
         }
     }
 
-    fn add_pool(&mut self, component_id: ComponentId, capacity: usize) {
-        self.pools
-            .insert(component_id, ComponentPool::with_capacity(capacity));
+    fn add_pool(
+        &mut self,
+        component_id: ComponentId,
+        indices: &ArrayViewIndices,
+        columns: Vec<PyRef<Column>>,
+        growth_factor: Option<f64>,
+        max_capacity: Option<usize>,
+    ) -> PyResult<()> {
+        for column in columns.iter() {
+            column.change_ticks.attach(&self.tick)?;
+        }
+        self.pools.insert(
+            component_id,
+            ComponentPool::new(
+                Arc::clone(&indices.0),
+                columns.iter().map(|column| column.share()).collect(),
+                growth_factor.map(|factor| Growth {
+                    factor,
+                    max_capacity,
+                }),
+            )?,
+        );
+        Ok(())
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn add_query(
         &mut self,
         first_component: ComponentId,
         other_components: Vec<ComponentId>,
+        with_components: Vec<ComponentId>,
+        without_components: Vec<ComponentId>,
+        optional_components: Vec<ComponentId>,
+        added_components: Vec<ComponentId>,
+        changed_components: Vec<ComponentId>,
     ) -> QueryId {
-        self.queries
-            .push(Query::new(first_component, other_components));
-        self.queries.len() - 1
+        let mut components = other_components;
+        components.insert(0, first_component);
+        // Components which are required but not fetched are simply
+        // not included in the result.
+        let mut required_components = components.clone();
+        required_components.extend(with_components);
+        required_components.extend(added_components.iter());
+        required_components.extend(changed_components.iter());
+        let query_key = (sorted(required_components), sorted(without_components));
+        let query_position = match self.query_positions.get(&query_key) {
+            Some(&query_position) => query_position,
+            None => {
+                let query_position = self.queries.len();
+                let (required_components, excluded_components) = query_key.clone();
+                for component_id in required_components.iter() {
+                    self.component_queries
+                        .entry(*component_id)
+                        .or_default()
+                        .push(query_position);
+                }
+                for component_id in excluded_components.iter() {
+                    self.excluded_component_queries
+                        .entry(*component_id)
+                        .or_default()
+                        .push(query_position);
+                }
+                self.queries.push(Query::new(
+                    required_components,
+                    excluded_components,
+                    &self.pools,
+                ));
+                self.query_positions.insert(query_key, query_position);
+                query_position
+            }
+        };
+        let query = unsafe { self.queries.get_unchecked(query_position) };
+        let component_positions = components
+            .iter()
+            .map(|component_id| {
+                query
+                    .components()
+                    .iter()
+                    .position(|query_component| query_component == component_id)
+                    .unwrap()
+            })
+            .collect();
+        self.query_accesses.push(QueryAccess {
+            query_position,
+            component_positions,
+            optional_components,
+            added_components,
+            changed_components,
+            last_run: 0,
+        });
+        self.query_accesses.len() - 1
     }
 
-    fn run_query(&self, query_id: QueryId) -> MultipleArrayViewIndices {
-        unsafe { self.queries.get_unchecked(query_id) }.result(&self.pools)
+    fn run_query(
+        &mut self,
+        py: Python,
+        query_id: QueryId,
+    ) -> PyResult<(MultipleArrayViewIndices, Vec<OptionalResult>)> {
+        let access = unsafe { self.query_accesses.get_unchecked(query_id) };
+        let query = unsafe { self.queries.get_unchecked(access.query_position) };
+        let keep = self.changed_entities(query, access)?;
+        let optional_results = access
+            .optional_components
+            .iter()
+            .map(|&component_id| {
+                let (mask, indices) =
+                    query.optional_result(component_id, &self.pools, keep.as_deref());
+                (
+                    PyArray1::from_vec(py, mask).to_owned(),
+                    ArrayViewIndices(Arc::new(RwLock::new(indices))),
+                )
+            })
+            .collect();
+        let result = query.result(&access.component_positions, keep.as_deref());
+        unsafe { self.query_accesses.get_unchecked_mut(query_id) }.last_run = self.tick.get();
+        Ok((result, optional_results))
+    }
+
+    fn add_removed_reader(&mut self, component_id: ComponentId) -> RemovedReaderId {
+        let reader_id = self.removed_readers.len();
+        self.removed_readers.push(Vec::new());
+        self.component_removed_readers
+            .entry(component_id)
+            .or_default()
+            .push(reader_id);
+        reader_id
+    }
+
+    /// Take the entities which lost the component of a reader since
+    /// it was last read.
+    fn read_removed(&mut self, py: Python, reader_id: RemovedReaderId) -> Py<PyArray1<u32>> {
+        let removed = std::mem::take(unsafe { self.removed_readers.get_unchecked_mut(reader_id) });
+        PyArray1::from_vec(py, removed).to_owned()
+    }
+
+    /// Move to the next change detection tick.
+    ///
+    /// This is done before each system runs, so that a system does not
+    /// see its own writes as changes the next time it runs.
+    fn advance_tick(&self) {
+        self.tick.advance();
+    }
+}
+
+impl RustApp {
+    fn entity_is_alive(&self, entity_id: EntityId) -> bool {
+        let index = entity_id::index(entity_id) as usize;
+        index < self.alive.len()
+            && unsafe { *self.alive.get_unchecked(index) }
+            && unsafe { *self.generations.get_unchecked(index) } == entity_id::generation(entity_id)
+    }
+
+    /// Get which matched entities pass the Added and Changed filters
+    /// of a query, or None if it has no such filters.
+    fn changed_entities(&self, query: &Query, access: &QueryAccess) -> PyResult<Option<Vec<bool>>> {
+        if access.added_components.is_empty() && access.changed_components.is_empty() {
+            return Ok(None);
+        }
+        let mut keep = vec![true; query.len()];
+        for &component_id in access.added_components.iter() {
+            let added = self
+                .pools
+                .get(&component_id)
+                .ok_or_else(|| missing_pool(component_id))?
+                .added_since(query.indices_of(component_id), access.last_run);
+            for (keep, added) in keep.iter_mut().zip(added) {
+                *keep &= added;
+            }
+        }
+        for &component_id in access.changed_components.iter() {
+            let changed = self
+                .pools
+                .get(&component_id)
+                .ok_or_else(|| missing_pool(component_id))?
+                .changed_since(query.indices_of(component_id), access.last_run)?;
+            for (keep, changed) in keep.iter_mut().zip(changed) {
+                *keep &= changed;
+            }
+        }
+        Ok(Some(keep))
+    }
+
+    fn check_alive(&self, entity_ids: &[EntityId]) -> PyResult<()> {
+        match entity_ids
+            .iter()
+            .find(|&&entity_id| !self.entity_is_alive(entity_id))
+        {
+            Some(&entity_id) => Err(PyKeyError::new_err(format!(
+                "entity {entity_id} (index {}, generation {}) is not alive",
+                entity_id::index(entity_id),
+                entity_id::generation(entity_id),
+            ))),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Get the positions of the queries which any of `components` map to.
+fn affected_queries(
+    component_queries: &Map<ComponentId, Vec<usize>>,
+    components: &[ComponentId],
+) -> Vec<usize> {
+    sorted(
+        components
+            .iter()
+            .filter_map(|component_id| component_queries.get(component_id))
+            .flatten()
+            .copied()
+            .collect(),
+    )
+}
+
+/// Add entities which lost a component to the readers of its removals.
+fn record_removed(
+    removed_readers: &mut [Vec<EntityId>],
+    component_removed_readers: &Map<ComponentId, Vec<RemovedReaderId>>,
+    component_id: ComponentId,
+    removed: &[EntityId],
+) {
+    if removed.is_empty() {
+        return;
     }
+    for &reader_id in component_removed_readers
+        .get(&component_id)
+        .into_iter()
+        .flatten()
+    {
+        unsafe { removed_readers.get_unchecked_mut(reader_id) }.extend_from_slice(removed);
+    }
+}
+
+fn sorted<T: Ord>(mut vec: Vec<T>) -> Vec<T> {
+    vec.sort_unstable();
+    vec.dedup();
+    vec
+}
+
+/// Check that no component appears more than once in `components`, so
+/// that entities are not added to the same pool twice.
+fn check_unique(components: &[ComponentId]) -> PyResult<()> {
+    let mut seen = Set::with_capacity(components.len());
+    match components
+        .iter()
+        .find(|&&component_id| !seen.insert(component_id))
+    {
+        Some(component_id) => Err(PyValueError::new_err(format!(
+            "component {component_id} is given more than once"
+        ))),
+        None => Ok(()),
+    }
+}
+
+fn missing_pool(component_id: ComponentId) -> PyErr {
+    PyKeyError::new_err(format!("missing a pool for component {component_id}"))
 }
@@ -1,6 +1,6 @@
 use crate::error_handlers::{cannot_read, cannot_write};
+use crate::getitem_key::{position, GetItemKey};
 use crate::index::Index;
-use numpy::PyArray1;
 use pyo3::exceptions::PyRuntimeError;
 use pyo3::prelude::*;
 use std::sync::{Arc, RwLock};
@@ -35,6 +35,20 @@ impl MultipleArrayViewIndices {
 #[pyclass(module = "xecs")]
 pub struct ArrayViewIndices(pub Arc<RwLock<Vec<Index>>>);
 
+impl ArrayViewIndices {
+    /// Get the index into the component pool of the element at `index`
+    /// in the view. Negative indices count back from the end.
+    pub fn get(&self, index: isize) -> PyResult<Index> {
+        let indices = self.0.read().map_err(cannot_read)?;
+        Ok(indices[position(index, indices.len())?])
+    }
+    /// Select the indices which `key` refers to.
+    pub fn select(&self, key: &GetItemKey) -> PyResult<Self> {
+        let indices = self.0.read().map_err(cannot_read)?;
+        Ok(Self(Arc::new(RwLock::new(key.select(&indices)?))))
+    }
+}
+
 #[pymethods]
 impl ArrayViewIndices {
     /// Construct a new, emtpy set of indices with a given capacity.
@@ -73,18 +87,17 @@ impl ArrayViewIndices {
         Ok(self.0.read().map_err(cannot_read)?.len())
     }
 
-    pub fn __getitem__(&self, key: &PyArray1<bool>) -> PyResult<Self> {
-        let indices = self.0.read().map_err(cannot_read)?;
-        // Ideally the capacity of new_indices would be the number of
-        // true values in key. However, because that would mean we count
-        // them first, we allocate for the worst-case scenario instead -- we
-        // assume all values in the key are true.
-        let mut new_indices = Vec::with_capacity(key.len());
-        for (&keep, &index) in key.readonly().as_array().iter().zip(indices.iter()) {
-            if keep {
-                new_indices.push(index);
-            }
-        }
-        Ok(Self(Arc::new(RwLock::new(new_indices))))
+    /// Select some of the indices.
+    ///
+    /// Parameters:
+    ///     key (int | slice | numpy.ndarray | list[int] | list[bool]):
+    ///         An integer, a slice, an array or list of integers, or a
+    ///         boolean mask as long as self. Negative integers count
+    ///         back from the end.
+    /// Returns:
+    ///     ArrayViewIndices: The selected indices, in the order `key`
+    ///     refers to them.
+    pub fn __getitem__(&self, key: GetItemKey) -> PyResult<Self> {
+        self.select(&key)
     }
 }
@@ -1,8 +1,10 @@
-use crate::error_handlers::cannot_read;
+use crate::change_ticks::ChangeTicks;
+use crate::column::Column;
+use crate::error_handlers::{cannot_read, check_length};
+use crate::getitem_key::GetItemKey;
 use crate::{array_view_indices::ArrayViewIndices, error_handlers::cannot_write};
-use itertools::izip;
 use numpy::PyArray1;
-use pyo3::exceptions::PyNotImplementedError;
+use pyo3::exceptions::{PyNotImplementedError, PyValueError};
 use pyo3::prelude::*;
 use pyo3::pyclass::CompareOp;
 use std::sync::{Arc, RwLock};
@@ -15,9 +17,24 @@ enum BoolRhs<'a> {
     VecBool(Vec<bool>),
 }
 
+impl BoolRhs<'_> {
+    /// Get the number of values, or `None` for a single value which is
+    /// used for every element.
+    fn len(&self) -> PyResult<Option<usize>> {
+        Ok(match self {
+            BoolRhs::BoolValue(_) => None,
+            BoolRhs::Bool(b) => Some(b.indices.0.read().map_err(cannot_read)?.len()),
+            BoolRhs::PyArrayBool(array) => Some(array.len()),
+            BoolRhs::VecBool(vec) => Some(vec.len()),
+        })
+    }
+}
+
 /// An array of boolean values.
 #[pyclass(module = "xecs")]
 pub struct Bool {
+    change_ticks: ChangeTicks,
+    default: bool,
     array: Arc<RwLock<Vec<bool>>>,
     indices: ArrayViewIndices,
 }
@@ -31,6 +48,8 @@ impl Bool {
     #[staticmethod]
     fn p_from_value(value: bool, num: usize) -> PyResult<Self> {
         Ok(Self {
+            change_ticks: ChangeTicks::new(num),
+            default: Self::p_default_value(),
             array: Arc::new(RwLock::new(vec![value; num])),
             indices: ArrayViewIndices(Arc::new(RwLock::new((0_u32..(num as u32)).collect()))),
         })
@@ -39,6 +58,8 @@ impl Bool {
     #[staticmethod]
     fn p_from_numpy(array: &PyArray1<bool>) -> PyResult<Self> {
         Ok(Self {
+            change_ticks: ChangeTicks::new(array.len()),
+            default: Self::p_default_value(),
             array: Arc::new(RwLock::new(array.to_vec()?)),
             indices: ArrayViewIndices(Arc::new(RwLock::new(
                 (0_u32..(array.len() as u32)).collect(),
@@ -47,24 +68,29 @@ impl Bool {
     }
     #[staticmethod]
     fn p_from_indices(indices: &ArrayViewIndices, default: bool) -> PyResult<Self> {
+        let capacity = indices.0.read().map_err(cannot_read)?.capacity();
         Ok(Self {
-            array: Arc::new(RwLock::new(vec![
-                default;
-                indices
-                    .0
-                    .read()
-                    .map_err(cannot_read)?
-                    .capacity()
-            ])),
+            change_ticks: ChangeTicks::new(capacity),
+            default,
+            array: Arc::new(RwLock::new(vec![default; capacity])),
             indices: ArrayViewIndices(Arc::clone(&indices.0)),
         })
     }
     fn p_new_view_with_indices(&self, indices: &ArrayViewIndices) -> Self {
         Self {
+            change_ticks: self.change_ticks.share(),
+            default: self.default,
             array: Arc::clone(&self.array),
             indices: ArrayViewIndices(Arc::clone(&indices.0)),
         }
     }
+    fn p_column(&self) -> Column {
+        Column::new(
+            self.change_ticks.share(),
+            Arc::clone(&self.array),
+            self.default,
+        )
+    }
     /// Copy the elements into a NumPy array.
     ///
     /// Returns:
@@ -82,56 +108,25 @@ impl Bool {
     ///
     /// Parameters:
     ///     values (bool | list[bool]): The new values.
+    /// Raises:
+    ///     ValueError: If the number of values does not match the length
+    ///         of the array.
     fn fill(&mut self, values: BoolRhs) -> PyResult<()> {
-        let mut array = self.array.write().map_err(cannot_write)?;
-        let indices = self.indices.0.read().map_err(cannot_read)?;
-        match values {
-            BoolRhs::BoolValue(other) => {
-                for &index in indices.iter() {
-                    unsafe {
-                        *array.get_unchecked_mut(index as usize) = other;
-                    }
-                }
-            }
-            BoolRhs::Bool(b) => {
-                if !Arc::ptr_eq(&self.array, &b.array) {
-                    let other_array = b.array.read().map_err(cannot_read)?;
-                    let other_indices = b.indices.0.read().map_err(cannot_read)?;
-                    for (&index, &other_index) in indices.iter().zip(other_indices.iter()) {
-                        unsafe {
-                            *array.get_unchecked_mut(index as usize) =
-                                *other_array.get_unchecked(other_index as usize);
-                        }
-                    }
-                }
-            }
-            BoolRhs::PyArrayBool(py_array) => {
-                for (&index, &value) in indices.iter().zip(py_array.readonly().as_array()) {
-                    unsafe {
-                        *array.get_unchecked_mut(index as usize) = value;
-                    }
-                }
-            }
-            BoolRhs::VecBool(vec) => {
-                for (&index, value) in indices.iter().zip(vec) {
-                    unsafe {
-                        *array.get_unchecked_mut(index as usize) = value;
-                    }
-                }
-            }
-        }
-        Ok(())
+        self.update_with(values, |_, b| b)
     }
     /// Get the value at a specific index.
     ///
     /// Parameters:
-    ///     index (int): The index where the value is located.
+    ///     index (int): The index where the value is located. Negative
+    ///         indices count back from the end.
     /// Returns:
     ///     bool: The value at `index`.
-    fn get(&self, index: usize) -> PyResult<bool> {
-        let indices = self.indices.0.read().map_err(cannot_read)?;
+    /// Raises:
+    ///     IndexError: If `index` is out of bounds.
+    fn get(&self, index: isize) -> PyResult<bool> {
+        let index = self.indices.get(index)?;
         let array = self.array.read().map_err(cannot_read)?;
-        Ok(unsafe { *array.get_unchecked(*indices.get_unchecked(index) as usize) })
+        Ok(array[index as usize])
     }
     /// Get a string representation.
     ///
@@ -164,77 +159,11 @@ impl Bool {
     fn __len__(&self) -> PyResult<usize> {
         Ok(self.indices.0.read().map_err(cannot_read)?.len())
     }
-    fn __getitem__(&self, key: &PyArray1<bool>) -> PyResult<Self> {
-        Ok(Self {
-            array: Arc::clone(&self.array),
-            indices: self.indices.__getitem__(key)?,
-        })
+    fn __getitem__(&self, key: GetItemKey) -> PyResult<Self> {
+        self.getitem(&key)
     }
-    fn __setitem__(&mut self, key: &PyArray1<bool>, rhs: BoolRhs) -> PyResult<()> {
-        let mut array = self.array.write().map_err(cannot_write)?;
-        let indices = self.indices.0.read().map_err(cannot_read)?;
-        let mask = key.readonly();
-        let mask = mask.as_array();
-        match rhs {
-            BoolRhs::BoolValue(other) => {
-                for (&index, &keep) in indices.iter().zip(mask) {
-                    if keep {
-                        unsafe {
-                            *array.get_unchecked_mut(index as usize) = other;
-                        }
-                    }
-                }
-            }
-            BoolRhs::Bool(b) => {
-                if Arc::ptr_eq(&self.array, &b.array) {
-                    let other_indices = b.indices.0.read().map_err(cannot_read)?;
-                    for (&index, &other_index, &keep) in
-                        izip!(indices.iter(), other_indices.iter(), mask.iter())
-                    {
-                        if keep {
-                            unsafe {
-                                let other = *array.get_unchecked(other_index as usize);
-                                *array.get_unchecked_mut(index as usize) = other;
-                            }
-                        }
-                    }
-                } else {
-                    let other_array = b.array.read().map_err(cannot_read)?;
-                    let other_indices = b.indices.0.read().map_err(cannot_read)?;
-                    for (&index, &other_index, &keep) in
-                        izip!(indices.iter(), other_indices.iter(), mask.iter())
-                    {
-                        if keep {
-                            unsafe {
-                                *array.get_unchecked_mut(index as usize) =
-                                    *other_array.get_unchecked(other_index as usize);
-                            }
-                        }
-                    }
-                }
-            }
-            BoolRhs::PyArrayBool(py_array) => {
-                for (&index, &value, &keep) in
-                    izip!(indices.iter(), py_array.readonly().as_array(), mask.iter())
-                {
-                    if keep {
-                        unsafe {
-                            *array.get_unchecked_mut(index as usize) = value;
-                        }
-                    }
-                }
-            }
-            BoolRhs::VecBool(vec) => {
-                for (&index, value, &keep) in izip!(indices.iter(), vec, mask.iter()) {
-                    if keep {
-                        unsafe {
-                            *array.get_unchecked_mut(index as usize) = value;
-                        }
-                    }
-                }
-            }
-        }
-        Ok(())
+    fn __setitem__(&mut self, key: GetItemKey, rhs: BoolRhs) -> PyResult<()> {
+        self.getitem(&key)?.update_with(rhs, |_, b| b)
     }
     fn __richcmp__(
         &self,
@@ -248,87 +177,208 @@ impl Bool {
             CompareOp::Gt => Err(PyNotImplementedError::new_err("> not implemented")),
             CompareOp::Ge => Err(PyNotImplementedError::new_err(">= not implemented")),
             CompareOp::Eq => {
-                let array = self.array.read().map_err(cannot_write)?;
-                let indices = self.indices.0.read().map_err(cannot_read)?;
-                let mut result = Vec::with_capacity(indices.len());
-                match other {
-                    BoolRhs::BoolValue(other) => {
-                        for &index in indices.iter() {
-                            unsafe {
-                                result.push(*array.get_unchecked(index as usize) == other);
-                            }
-                        }
-                    }
-                    BoolRhs::Bool(b) => {
-                        let other_array = b.array.read().map_err(cannot_read)?;
-                        let other_indices = b.indices.0.read().map_err(cannot_read)?;
-                        for (&index, &other_index) in indices.iter().zip(other_indices.iter()) {
-                            unsafe {
-                                result.push(
-                                    array.get_unchecked(index as usize)
-                                        == other_array.get_unchecked(other_index as usize),
-                                );
-                            }
-                        }
-                    }
-                    BoolRhs::PyArrayBool(py_array) => {
-                        for (&index, value) in indices.iter().zip(py_array.readonly().as_array()) {
-                            unsafe {
-                                result.push(array.get_unchecked(index as usize) == value);
-                            }
-                        }
-                    }
-                    BoolRhs::VecBool(vec) => {
-                        for (&index, value) in indices.iter().zip(vec) {
-                            unsafe {
-                                result.push(*array.get_unchecked(index as usize) == value);
-                            }
-                        }
-                    }
-                }
-                Ok(PyArray1::from_vec(py, result).into_py(py))
+                Ok(PyArray1::from_vec(py, self.zip(other, |a, b| a == b)?).into_py(py))
             }
             CompareOp::Ne => {
-                let array = self.array.read().map_err(cannot_write)?;
-                let indices = self.indices.0.read().map_err(cannot_read)?;
-                let mut result = Vec::with_capacity(indices.len());
-                match other {
-                    BoolRhs::BoolValue(other) => {
-                        for &index in indices.iter() {
-                            unsafe {
-                                result.push(*array.get_unchecked(index as usize) != other);
-                            }
-                        }
-                    }
-                    BoolRhs::Bool(b) => {
-                        let other_array = b.array.read().map_err(cannot_read)?;
-                        let other_indices = b.indices.0.read().map_err(cannot_read)?;
-                        for (&index, &other_index) in indices.iter().zip(other_indices.iter()) {
-                            unsafe {
-                                result.push(
-                                    array.get_unchecked(index as usize)
-                                        != other_array.get_unchecked(other_index as usize),
-                                );
-                            }
-                        }
-                    }
-                    BoolRhs::PyArrayBool(py_array) => {
-                        for (&index, value) in indices.iter().zip(py_array.readonly().as_array()) {
-                            unsafe {
-                                result.push(array.get_unchecked(index as usize) != value);
-                            }
-                        }
-                    }
-                    BoolRhs::VecBool(vec) => {
-                        for (&index, value) in indices.iter().zip(vec) {
-                            unsafe {
-                                result.push(*array.get_unchecked(index as usize) != value);
-                            }
-                        }
-                    }
+                Ok(PyArray1::from_vec(py, self.zip(other, |a, b| a != b)?).into_py(py))
+            }
+        }
+    }
+    fn __and__(&self, py: Python, rhs: BoolRhs) -> PyResult<Py<PyArray1<bool>>> {
+        Ok(PyArray1::from_vec(py, self.zip(rhs, |a, b| a & b)?).into_py(py))
+    }
+    fn __iand__(&mut self, rhs: BoolRhs) -> PyResult<()> {
+        self.update_with(rhs, |a, b| a & b)
+    }
+    fn __or__(&self, py: Python, rhs: BoolRhs) -> PyResult<Py<PyArray1<bool>>> {
+        Ok(PyArray1::from_vec(py, self.zip(rhs, |a, b| a | b)?).into_py(py))
+    }
+    fn __ior__(&mut self, rhs: BoolRhs) -> PyResult<()> {
+        self.update_with(rhs, |a, b| a | b)
+    }
+    fn __xor__(&self, py: Python, rhs: BoolRhs) -> PyResult<Py<PyArray1<bool>>> {
+        Ok(PyArray1::from_vec(py, self.zip(rhs, |a, b| a ^ b)?).into_py(py))
+    }
+    fn __ixor__(&mut self, rhs: BoolRhs) -> PyResult<()> {
+        self.update_with(rhs, |a, b| a ^ b)
+    }
+    fn __invert__(&self, py: Python) -> PyResult<Py<PyArray1<bool>>> {
+        Ok(PyArray1::from_vec(py, self.zip(BoolRhs::BoolValue(true), |a, _| !a)?).into_py(py))
+    }
+    /// Negate the elements in-place.
+    fn invert_in_place(&mut self) -> PyResult<()> {
+        self.update_with(BoolRhs::BoolValue(true), |a, _| !a)
+    }
+    /// Count the elements which are :data:`True`.
+    ///
+    /// Returns:
+    ///     int: The number of :data:`True` elements.
+    fn count_true(&self) -> PyResult<usize> {
+        let array = self.array.read().map_err(cannot_read)?;
+        let indices = self.indices.0.read().map_err(cannot_read)?;
+        let count = indices
+            .iter()
+            .filter(|&&index| unsafe { *array.get_unchecked(index as usize) })
+            .count();
+        Ok(count)
+    }
+    /// Add up the elements, counting :data:`True` as 1.
+    ///
+    /// Returns:
+    ///     int: The sum, which is 0 if the array is empty.
+    fn sum(&self) -> PyResult<usize> {
+        self.count_true()
+    }
+    /// Average the elements, counting :data:`True` as 1.
+    ///
+    /// Returns:
+    ///     float: The mean, which is NaN if the array is empty.
+    fn mean(&self) -> PyResult<f64> {
+        Ok(self.count_true()? as f64 / self.__len__()? as f64)
+    }
+    /// Find the smallest element.
+    ///
+    /// Returns:
+    ///     bool: :data:`False` if any element is :data:`False`.
+    /// Raises:
+    ///     ValueError: If the array is empty.
+    fn min(&self) -> PyResult<bool> {
+        self.get(self.argmin()? as isize)
+    }
+    /// Find the largest element.
+    ///
+    /// Returns:
+    ///     bool: :data:`True` if any element is :data:`True`.
+    /// Raises:
+    ///     ValueError: If the array is empty.
+    fn max(&self) -> PyResult<bool> {
+        self.get(self.argmax()? as isize)
+    }
+    /// Find the index of the first smallest element.
+    ///
+    /// Returns:
+    ///     int: The index into this array.
+    /// Raises:
+    ///     ValueError: If the array is empty.
+    fn argmin(&self) -> PyResult<usize> {
+        self.first_or_zero(false)
+    }
+    /// Find the index of the first largest element.
+    ///
+    /// Returns:
+    ///     int: The index into this array.
+    /// Raises:
+    ///     ValueError: If the array is empty.
+    fn argmax(&self) -> PyResult<usize> {
+        self.first_or_zero(true)
+    }
+    /// Check if any element is :data:`True`.
+    ///
+    /// Returns:
+    ///     bool: :data:`False` if the array is empty.
+    fn any(&self) -> PyResult<bool> {
+        Ok(self.position(true)?.is_some())
+    }
+    /// Check if every element is :data:`True`.
+    ///
+    /// Returns:
+    ///     bool: :data:`True` if the array is empty.
+    fn all(&self) -> PyResult<bool> {
+        Ok(self.position(false)?.is_none())
+    }
+}
+
+impl Bool {
+    /// Copy the elements in the view.
+    pub(crate) fn values(&self) -> PyResult<Vec<bool>> {
+        let array = self.array.read().map_err(cannot_read)?;
+        let indices = self.indices.0.read().map_err(cannot_read)?;
+        let values = indices
+            .iter()
+            .map(|&index| unsafe { *array.get_unchecked(index as usize) })
+            .collect();
+        Ok(values)
+    }
+    /// Apply `f` to every element in the view and the matching element
+    /// of `rhs`.
+    fn zip(&self, rhs: BoolRhs, f: impl Fn(bool, bool) -> bool) -> PyResult<Vec<bool>> {
+        self.check_length(&rhs)?;
+        let array = self.array.read().map_err(cannot_read)?;
+        let indices = self.indices.0.read().map_err(cannot_read)?;
+        let lhs = indices
+            .iter()
+            .map(|&index| unsafe { *array.get_unchecked(index as usize) });
+        let result = match rhs {
+            BoolRhs::BoolValue(other) => lhs.map(|a| f(a, other)).collect(),
+            BoolRhs::Bool(b) => {
+                let other_indices = b.indices.0.read().map_err(cannot_read)?;
+                if Arc::ptr_eq(&self.array, &b.array) {
+                    lhs.zip(other_indices.iter())
+                        .map(|(a, &index)| f(a, unsafe { *array.get_unchecked(index as usize) }))
+                        .collect()
+                } else {
+                    let other_array = b.array.read().map_err(cannot_read)?;
+                    lhs.zip(other_indices.iter())
+                        .map(|(a, &index)| {
+                            f(a, unsafe { *other_array.get_unchecked(index as usize) })
+                        })
+                        .collect()
                 }
-                Ok(PyArray1::from_vec(py, result).into_py(py))
             }
+            BoolRhs::PyArrayBool(py_array) => lhs
+                .zip(py_array.readonly().as_array())
+                .map(|(a, &b)| f(a, b))
+                .collect(),
+            BoolRhs::VecBool(vec) => lhs.zip(vec).map(|(a, b)| f(a, b)).collect(),
+        };
+        Ok(result)
+    }
+    /// Replace every element in the view with the result of `f` applied
+    /// to it and the matching element of `rhs`.
+    fn update_with(&self, rhs: BoolRhs, f: impl Fn(bool, bool) -> bool) -> PyResult<()> {
+        let result = self.zip(rhs, f)?;
+        let mut array = self.array.write().map_err(cannot_write)?;
+        let indices = self.indices.0.read().map_err(cannot_read)?;
+        for (&index, value) in indices.iter().zip(result) {
+            unsafe {
+                *array.get_unchecked_mut(index as usize) = value;
+            }
+        }
+        self.change_ticks.mark(&indices)
+    }
+    /// Select the elements `key` refers to.
+    fn getitem(&self, key: &GetItemKey) -> PyResult<Self> {
+        Ok(Self {
+            change_ticks: self.change_ticks.share(),
+            default: self.default,
+            array: Arc::clone(&self.array),
+            indices: self.indices.select(key)?,
+        })
+    }
+    /// Check that `rhs` has a value for every element in the view.
+    fn check_length(&self, rhs: &BoolRhs) -> PyResult<()> {
+        match rhs.len()? {
+            Some(len) => check_length(self.__len__()?, len),
+            None => Ok(()),
+        }
+    }
+    /// Find the position of the first element equal to `value`.
+    fn position(&self, value: bool) -> PyResult<Option<usize>> {
+        let array = self.array.read().map_err(cannot_read)?;
+        let indices = self.indices.0.read().map_err(cannot_read)?;
+        let position = indices
+            .iter()
+            .position(|&index| unsafe { *array.get_unchecked(index as usize) } == value);
+        Ok(position)
+    }
+    /// Like [`Bool::position`], but fall back to the first element if no
+    /// element is equal to `value`, as all elements are then equally good.
+    fn first_or_zero(&self, value: bool) -> PyResult<usize> {
+        if self.__len__()? == 0 {
+            return Err(PyValueError::new_err(
+                "attempt to reduce an empty view, which has no identity",
+            ));
         }
+        Ok(self.position(value)?.unwrap_or(0))
     }
 }
@@ -0,0 +1,62 @@
+use crate::error_handlers::{cannot_read, cannot_write};
+use crate::index::Index;
+use crate::tick::{CurrentTick, Tick};
+use pyo3::prelude::*;
+use std::sync::{Arc, RwLock};
+
+/// The tick at which each element of a column was last written.
+pub struct ChangeTicks {
+    ticks: Arc<RwLock<Vec<Tick>>>,
+    // The tick writes are recorded at, which is replaced by the tick of
+    // the app once the column's pool is added to one.
+    current: Arc<RwLock<CurrentTick>>,
+}
+
+impl ChangeTicks {
+    pub fn new(len: usize) -> Self {
+        Self {
+            ticks: Arc::new(RwLock::new(vec![0; len])),
+            current: Arc::new(RwLock::new(CurrentTick::default())),
+        }
+    }
+    /// Get another handle to the same ticks.
+    pub fn share(&self) -> Self {
+        Self {
+            ticks: Arc::clone(&self.ticks),
+            current: Arc::clone(&self.current),
+        }
+    }
+    /// Record writes at `current` from now on, in every view of the
+    /// column.
+    pub fn attach(&self, current: &CurrentTick) -> PyResult<()> {
+        *self.current.write().map_err(cannot_write)? = current.clone();
+        Ok(())
+    }
+    pub fn resize(&self, len: usize) -> PyResult<()> {
+        self.ticks.write().map_err(cannot_write)?.resize(len, 0);
+        Ok(())
+    }
+    /// Record that the elements at `indices` were written.
+    pub fn mark(&self, indices: &[Index]) -> PyResult<()> {
+        let mut ticks = self.ticks.write().map_err(cannot_write)?;
+        let current = self.current.read().map_err(cannot_read)?.get();
+        for &index in indices {
+            unsafe { *ticks.get_unchecked_mut(index as usize) = current };
+        }
+        Ok(())
+    }
+    /// Set `changed` to true for the elements at `indices` which were
+    /// written after `since`.
+    pub fn changed_since(
+        &self,
+        indices: &[Index],
+        since: Tick,
+        changed: &mut [bool],
+    ) -> PyResult<()> {
+        let ticks = self.ticks.read().map_err(cannot_read)?;
+        for (&index, changed) in indices.iter().zip(changed.iter_mut()) {
+            *changed |= unsafe { *ticks.get_unchecked(index as usize) } > since;
+        }
+        Ok(())
+    }
+}
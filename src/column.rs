@@ -0,0 +1,55 @@
+use crate::change_ticks::ChangeTicks;
+use crate::error_handlers::cannot_write;
+use pyo3::prelude::*;
+use std::sync::{Arc, RwLock};
+
+trait Resize: Send + Sync {
+    fn resize(&self, len: usize) -> PyResult<()>;
+}
+
+struct Array<T> {
+    array: Arc<RwLock<Vec<T>>>,
+    default: T,
+}
+
+impl<T: Clone + Send + Sync> Resize for Array<T> {
+    fn resize(&self, len: usize) -> PyResult<()> {
+        self.array
+            .write()
+            .map_err(cannot_write)?
+            .resize(len, self.default.clone());
+        Ok(())
+    }
+}
+
+/// A column of a component pool, which is resized when the pool grows.
+#[pyclass(module = "xecs")]
+pub struct Column {
+    pub change_ticks: ChangeTicks,
+    array: Arc<dyn Resize>,
+}
+
+impl Column {
+    pub fn new<T: Clone + Send + Sync + 'static>(
+        change_ticks: ChangeTicks,
+        array: Arc<RwLock<Vec<T>>>,
+        default: T,
+    ) -> Self {
+        Self {
+            change_ticks,
+            array: Arc::new(Array { array, default }),
+        }
+    }
+    /// Get another handle to the same column.
+    pub fn share(&self) -> Self {
+        Self {
+            change_ticks: self.change_ticks.share(),
+            array: Arc::clone(&self.array),
+        }
+    }
+    /// Resize the column, filling new elements with its default value.
+    pub fn resize(&self, len: usize) -> PyResult<()> {
+        self.array.resize(len)?;
+        self.change_ticks.resize(len)
+    }
+}
@@ -1,48 +1,215 @@
-use std::sync::{Arc, RwLock};
+use std::ops::Deref;
+use std::sync::{Arc, RwLock, RwLockReadGuard};
 
 use itertools::Itertools;
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 
-use crate::{array_view_indices::ArrayViewIndices, error_handlers::cannot_read};
+use crate::{array_view_indices::ArrayViewIndices, error_handlers::cannot_read, index::Index};
 
-#[pyfunction]
-pub fn product_2(
-    indices: Vec<PyRef<ArrayViewIndices>>,
-) -> PyResult<(Vec<ArrayViewIndices>, Vec<ArrayViewIndices>)> {
-    if indices.is_empty() {
-        return Ok((Vec::new(), Vec::new()));
+pub type Pairs = (Vec<ArrayViewIndices>, Vec<ArrayViewIndices>);
+
+type PairPositions = Box<dyn Iterator<Item = (usize, usize)> + Send>;
+
+/// An iterator over pairs of entities, which gives them in chunks of at
+/// most `chunk_size` pairs instead of all at once.
+///
+/// Each chunk has the same form as the result of :func:`product_2`, and
+/// the chunks together hold the same pairs in the same order. The
+/// entities are the ones matching the query when the iterator was
+/// created.
+#[pyclass(module = "xecs")]
+pub struct PairChunks {
+    indices1: Vec<Vec<Index>>,
+    indices2: Vec<Vec<Index>>,
+    pairs: PairPositions,
+    chunk_size: usize,
+}
+
+#[pymethods]
+impl PairChunks {
+    fn __iter__(slf: PyRef<Self>) -> PyRef<Self> {
+        slf
     }
-    let mut read_indices = Vec::with_capacity(indices.len());
-    for i in indices.iter() {
-        read_indices.push(i.0.read().map_err(cannot_read)?);
+    fn __next__(&mut self) -> Option<Pairs> {
+        let pairs: Vec<_> = self.pairs.by_ref().take(self.chunk_size).collect();
+        if pairs.is_empty() {
+            return None;
+        }
+        let indices1: Vec<_> = self.indices1.iter().collect();
+        let indices2: Vec<_> = self.indices2.iter().collect();
+        Some(gather_pairs(
+            &indices1,
+            &indices2,
+            pairs.len(),
+            pairs.into_iter(),
+        ))
     }
-    let num_entities = read_indices.first().unwrap().len();
-    let mut indices1 = Vec::with_capacity(indices.len());
-    let mut indices2 = Vec::with_capacity(indices.len());
-    let num_indices = (1..num_entities).sum();
-    for _ in 0..indices.len() {
-        indices1.push(Vec::with_capacity(num_indices));
-        indices2.push(Vec::with_capacity(num_indices));
+}
+
+impl PairChunks {
+    fn new(
+        indices1: &[RwLockReadGuard<Vec<Index>>],
+        indices2: &[RwLockReadGuard<Vec<Index>>],
+        pairs: PairPositions,
+        chunk_size: usize,
+    ) -> PyResult<Self> {
+        if chunk_size == 0 {
+            return Err(PyValueError::new_err("chunk_size must be positive"));
+        }
+        let copy = |indices: &[RwLockReadGuard<Vec<Index>>]| {
+            indices.iter().map(|indices| indices.to_vec()).collect()
+        };
+        Ok(Self {
+            indices1: copy(indices1),
+            indices2: copy(indices2),
+            pairs,
+            chunk_size,
+        })
     }
-    for (i, j) in (0..num_entities).cartesian_product(0..num_entities) {
-        if i == j {
-            continue;
+}
+
+/// Pair every entity with every other entity, in both orders.
+#[pyfunction]
+pub fn product_2(indices: Vec<PyRef<ArrayViewIndices>>) -> PyResult<Pairs> {
+    let indices = read_all(&indices)?;
+    let num_entities = num_entities(&indices);
+    let pairs = (0..num_entities)
+        .cartesian_product(0..num_entities)
+        .filter(|(i, j)| i != j);
+    let num_pairs = num_entities * num_entities.saturating_sub(1);
+    Ok(gather_pairs(&indices, &indices, num_pairs, pairs))
+}
+
+/// Pair every entity with every later entity, so that each unordered
+/// pair appears once.
+#[pyfunction]
+pub fn combinations_2(indices: Vec<PyRef<ArrayViewIndices>>) -> PyResult<Pairs> {
+    let indices = read_all(&indices)?;
+    let num_entities = num_entities(&indices);
+    let pairs = (0..num_entities).tuple_combinations();
+    let num_pairs = num_entities * num_entities.saturating_sub(1) / 2;
+    Ok(gather_pairs(&indices, &indices, num_pairs, pairs))
+}
+
+/// Pair every entity of one query with every entity of another.
+#[pyfunction]
+pub fn product_with(
+    indices1: Vec<PyRef<ArrayViewIndices>>,
+    indices2: Vec<PyRef<ArrayViewIndices>>,
+) -> PyResult<Pairs> {
+    let indices1 = read_all(&indices1)?;
+    let indices2 = read_all(&indices2)?;
+    let num_entities1 = num_entities(&indices1);
+    let num_entities2 = num_entities(&indices2);
+    let pairs = (0..num_entities1).cartesian_product(0..num_entities2);
+    let num_pairs = num_entities1 * num_entities2;
+    Ok(gather_pairs(&indices1, &indices2, num_pairs, pairs))
+}
+
+/// Like :func:`product_2`, but give the pairs in chunks of at most
+/// `chunk_size` pairs.
+#[pyfunction]
+pub fn product_2_chunks(
+    indices: Vec<PyRef<ArrayViewIndices>>,
+    chunk_size: usize,
+) -> PyResult<PairChunks> {
+    let indices = read_all(&indices)?;
+    let num_entities = num_entities(&indices);
+    let pairs = (0..num_entities)
+        .cartesian_product(0..num_entities)
+        .filter(|(i, j)| i != j);
+    PairChunks::new(&indices, &indices, Box::new(pairs), chunk_size)
+}
+
+/// Like :func:`combinations_2`, but give the pairs in chunks of at most
+/// `chunk_size` pairs.
+#[pyfunction]
+pub fn combinations_2_chunks(
+    indices: Vec<PyRef<ArrayViewIndices>>,
+    chunk_size: usize,
+) -> PyResult<PairChunks> {
+    let indices = read_all(&indices)?;
+    let pairs = (0..num_entities(&indices)).tuple_combinations();
+    PairChunks::new(&indices, &indices, Box::new(pairs), chunk_size)
+}
+
+/// Like :func:`product_with`, but give the pairs in chunks of at most
+/// `chunk_size` pairs.
+#[pyfunction]
+pub fn product_with_chunks(
+    indices1: Vec<PyRef<ArrayViewIndices>>,
+    indices2: Vec<PyRef<ArrayViewIndices>>,
+    chunk_size: usize,
+) -> PyResult<PairChunks> {
+    let indices1 = read_all(&indices1)?;
+    let indices2 = read_all(&indices2)?;
+    let pairs = (0..num_entities(&indices1)).cartesian_product(0..num_entities(&indices2));
+    PairChunks::new(&indices1, &indices2, Box::new(pairs), chunk_size)
+}
+
+pub fn read_all<'a>(
+    indices: &'a [PyRef<ArrayViewIndices>],
+) -> PyResult<Vec<RwLockReadGuard<'a, Vec<Index>>>> {
+    indices
+        .iter()
+        .map(|indices| indices.0.read().map_err(cannot_read))
+        .collect()
+}
+
+/// Get the number of entities in a query result, whose components all
+/// have the same number of indices.
+pub fn num_entities(indices: &[RwLockReadGuard<Vec<Index>>]) -> usize {
+    indices.first().map_or(0, |indices| indices.len())
+}
+
+/// Gather the indices of each component for the pairs of entity
+/// positions in `pairs`, of which there are `num_pairs`.
+pub fn gather_pairs(
+    indices1: &[impl Deref<Target = Vec<Index>>],
+    indices2: &[impl Deref<Target = Vec<Index>>],
+    num_pairs: usize,
+    pairs: impl Iterator<Item = (usize, usize)>,
+) -> Pairs {
+    let mut pair_indices1: Vec<_> = indices1
+        .iter()
+        .map(|_| Vec::with_capacity(num_pairs))
+        .collect();
+    let mut pair_indices2: Vec<_> = indices2
+        .iter()
+        .map(|_| Vec::with_capacity(num_pairs))
+        .collect();
+    for (i, j) in pairs {
+        for (pair_indices, indices) in pair_indices1.iter_mut().zip(indices1) {
+            pair_indices.push(unsafe { *indices.get_unchecked(i) });
         }
-        for (component_index, indices) in read_indices.iter().enumerate() {
-            unsafe {
-                indices1
-                    .get_unchecked_mut(component_index)
-                    .push(*indices.get_unchecked(i));
-                indices2
-                    .get_unchecked_mut(component_index)
-                    .push(*indices.get_unchecked(j));
-            }
+        for (pair_indices, indices) in pair_indices2.iter_mut().zip(indices2) {
+            pair_indices.push(unsafe { *indices.get_unchecked(j) });
         }
     }
-    Ok((
-        indices1.into_iter().map(vec_to_indices).collect(),
-        indices2.into_iter().map(vec_to_indices).collect(),
-    ))
+    (
+        pair_indices1.into_iter().map(vec_to_indices).collect(),
+        pair_indices2.into_iter().map(vec_to_indices).collect(),
+    )
+}
+
+/// Gather the indices of each component for the entity positions in
+/// `positions`.
+pub fn gather(
+    indices: &[RwLockReadGuard<Vec<Index>>],
+    positions: &[usize],
+) -> Vec<ArrayViewIndices> {
+    indices
+        .iter()
+        .map(|indices| {
+            vec_to_indices(
+                positions
+                    .iter()
+                    .map(|&position| unsafe { *indices.get_unchecked(position) })
+                    .collect(),
+            )
+        })
+        .collect()
 }
 
 fn vec_to_indices(vec: Vec<u32>) -> ArrayViewIndices {
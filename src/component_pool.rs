@@ -1,29 +1,170 @@
+use crate::column::Column;
 use crate::entity_id::EntityId;
+use crate::error_handlers::{cannot_read, cannot_write};
 use crate::index::Index;
 use crate::map::Map;
 use crate::set::Set;
+use crate::tick::Tick;
+use pyo3::exceptions::PyRuntimeError;
+use pyo3::prelude::*;
+use std::sync::{Arc, RwLock};
 
 pub struct ComponentPool {
     pub entity_indices: Map<EntityId, Index>,
     pub entity_ids: Set<EntityId>,
+    // The indices of every component in the pool. This is shared with
+    // the Python component pool, so that views of the whole pool see
+    // spawned and despawned entities.
+    indices: Arc<RwLock<Vec<Index>>>,
+    // Indices which belonged to despawned entities and can be reused.
+    free_indices: Vec<Index>,
+    // The tick at which the entity at each index was added.
+    added_ticks: Vec<Tick>,
+    // The columns of the component, which are resized together when
+    // the pool grows.
+    columns: Vec<Column>,
+    // The number of entities the columns can hold.
+    capacity: usize,
+    // How the pool grows when it is full, or None if it cannot grow.
+    growth: Option<Growth>,
+}
+
+/// How a component pool grows when it is full.
+pub struct Growth {
+    /// The factor the capacity is multiplied by when the pool grows.
+    pub factor: f64,
+    /// The capacity the pool cannot grow beyond.
+    pub max_capacity: Option<usize>,
 }
 
 impl ComponentPool {
-    pub fn add_entities(&mut self, entity_ids: &Vec<EntityId>) {
-        let new_indices = (self.entity_ids.len() as Index)
-            ..(self.entity_ids.len() as Index) + (entity_ids.len() as Index);
+    pub fn new(
+        indices: Arc<RwLock<Vec<Index>>>,
+        columns: Vec<Column>,
+        growth: Option<Growth>,
+    ) -> PyResult<Self> {
+        let capacity = indices.read().map_err(cannot_read)?.capacity();
+        Ok(Self {
+            entity_indices: Map::with_capacity(capacity),
+            entity_ids: Set::with_capacity(capacity),
+            indices,
+            free_indices: Vec::new(),
+            added_ticks: vec![0; capacity],
+            columns,
+            capacity,
+            growth,
+        })
+    }
+    /// Make sure the pool has room for `num` more entities, growing it
+    /// if it is full and allowed to grow.
+    pub fn reserve(&mut self, num: usize) -> PyResult<()> {
+        let required = self.indices.read().map_err(cannot_read)?.len() + num;
+        if required <= self.capacity {
+            return Ok(());
+        }
+        let Some(growth) = &self.growth else {
+            return Err(PyRuntimeError::new_err(
+                "cannot spawn more entities because pool is full",
+            ));
+        };
+        let max_capacity = growth.max_capacity.unwrap_or(usize::MAX);
+        if required > max_capacity {
+            return Err(PyRuntimeError::new_err(format!(
+                "cannot spawn more entities because pool has reached its \
+                maximum capacity of {max_capacity}"
+            )));
+        }
+        let grown_capacity = (self.capacity as f64 * growth.factor).ceil() as usize;
+        self.grow(grown_capacity.max(required).min(max_capacity))
+    }
+    fn grow(&mut self, capacity: usize) -> PyResult<()> {
+        for column in self.columns.iter() {
+            column.resize(capacity)?;
+        }
+        self.added_ticks.resize(capacity, 0);
+        let mut indices = self.indices.write().map_err(cannot_write)?;
+        let num_indices = indices.len();
+        indices.reserve_exact(capacity - num_indices);
+        self.capacity = capacity;
+        Ok(())
+    }
+    /// Add entities to the pool, recording that they were added at
+    /// `tick`, and return their indices.
+    pub fn add_entities(&mut self, entity_ids: &[EntityId], tick: Tick) -> PyResult<Vec<Index>> {
+        let mut indices = self.indices.write().map_err(cannot_write)?;
+        let num_used_indices = (indices.len() + self.free_indices.len()) as Index;
+        let num_reused = entity_ids.len().min(self.free_indices.len());
+        let mut new_indices: Vec<_> = self
+            .free_indices
+            .drain(self.free_indices.len() - num_reused..)
+            .collect();
+        new_indices
+            .extend(num_used_indices..num_used_indices + (entity_ids.len() - num_reused) as Index);
+        indices.extend(new_indices.iter());
+        for &index in new_indices.iter() {
+            unsafe { *self.added_ticks.get_unchecked_mut(index as usize) = tick };
+        }
         self.entity_ids.extend(entity_ids);
         entity_ids
             .iter()
-            .zip(new_indices)
+            .zip(new_indices.iter())
             .for_each(|(entity_id, index)| {
-                self.entity_indices.insert(*entity_id, index);
+                self.entity_indices.insert(*entity_id, *index);
+            });
+        Ok(new_indices)
+    }
+    /// Get the entities which are not in the pool, without duplicates.
+    pub fn missing_entities(&self, entity_ids: &[EntityId]) -> Vec<EntityId> {
+        let mut missing = Set::new();
+        entity_ids
+            .iter()
+            .filter(|&&entity_id| {
+                !self.entity_ids.contains(&entity_id) && missing.insert(entity_id)
             })
+            .copied()
+            .collect()
     }
-    pub fn with_capacity(capacity: usize) -> Self {
-        Self {
-            entity_indices: Map::with_capacity(capacity),
-            entity_ids: Set::with_capacity(capacity),
+    /// Get the index of each entity. All entities must be in the pool.
+    pub fn entity_indices(&self, entity_ids: &[EntityId]) -> Vec<Index> {
+        entity_ids
+            .iter()
+            .map(|entity_id| *self.entity_indices.get(entity_id).unwrap())
+            .collect()
+    }
+    /// Get whether each of `indices` was added after `since`.
+    pub fn added_since(&self, indices: &[Index], since: Tick) -> Vec<bool> {
+        indices
+            .iter()
+            .map(|&index| unsafe { *self.added_ticks.get_unchecked(index as usize) } > since)
+            .collect()
+    }
+    /// Get whether each of `indices` was added or had any of its
+    /// columns written after `since`.
+    pub fn changed_since(&self, indices: &[Index], since: Tick) -> PyResult<Vec<bool>> {
+        let mut changed = self.added_since(indices, since);
+        for column in self.columns.iter() {
+            column
+                .change_ticks
+                .changed_since(indices, since, &mut changed)?;
+        }
+        Ok(changed)
+    }
+    /// Remove entities from the pool, returning the ones which were in it.
+    pub fn remove_entities(&mut self, entity_ids: &[EntityId]) -> PyResult<Vec<EntityId>> {
+        let num_free_indices = self.free_indices.len();
+        let mut removed = Vec::new();
+        for entity_id in entity_ids {
+            if let Some(index) = self.entity_indices.remove(entity_id) {
+                self.entity_ids.remove(entity_id);
+                self.free_indices.push(index);
+                removed.push(*entity_id);
+            }
+        }
+        if self.free_indices.len() > num_free_indices {
+            let freed_indices: Set<_> = self.free_indices[num_free_indices..].iter().collect();
+            let mut indices = self.indices.write().map_err(cannot_write)?;
+            indices.retain(|index| !freed_indices.contains(index));
         }
+        Ok(removed)
     }
 }
@@ -1,2 +1,25 @@
+/// An entity id packs the index of the entity into its lower bits and
+/// the generation of the index into its upper bits. The generation is
+/// incremented every time the entity at an index is despawned, so
+/// that ids of despawned entities can be told apart from ids of
+/// entities which reuse the index. An index whose generation cannot be
+/// incremented any further is not reused.
 pub type EntityId = u32;
 
+const INDEX_BITS: u32 = 24;
+const INDEX_MASK: u32 = (1 << INDEX_BITS) - 1;
+
+/// The maximum number of entity indices.
+pub const MAX_ENTITIES: usize = 1 << INDEX_BITS;
+
+pub fn new(index: u32, generation: u8) -> EntityId {
+    ((generation as u32) << INDEX_BITS) | index
+}
+
+pub fn index(entity_id: EntityId) -> u32 {
+    entity_id & INDEX_MASK
+}
+
+pub fn generation(entity_id: EntityId) -> u8 {
+    (entity_id >> INDEX_BITS) as u8
+}
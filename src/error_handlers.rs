@@ -1,4 +1,6 @@
-use pyo3::exceptions::PyRuntimeError;
+use pyo3::exceptions::{
+    PyIndexError, PyOverflowError, PyRuntimeError, PyValueError, PyZeroDivisionError,
+};
 use pyo3::prelude::*;
 
 pub fn cannot_write<T>(_err: T) -> PyErr {
@@ -8,3 +10,28 @@ pub fn cannot_write<T>(_err: T) -> PyErr {
 pub fn cannot_read<T>(_err: T) -> PyErr {
     PyRuntimeError::new_err("cannot read array")
 }
+
+pub fn overflowed(operation: &str) -> PyErr {
+    PyOverflowError::new_err(format!("integer {operation} overflowed"))
+}
+
+pub fn divided_by_zero() -> PyErr {
+    PyZeroDivisionError::new_err("integer division or modulo by zero")
+}
+
+pub fn index_out_of_bounds(index: isize, len: usize) -> PyErr {
+    PyIndexError::new_err(format!(
+        "index {index} is out of bounds for a view of length {len}"
+    ))
+}
+
+/// Check that `len` values were given for a view of length `expected`.
+pub fn check_length(expected: usize, len: usize) -> PyResult<()> {
+    if len == expected {
+        Ok(())
+    } else {
+        Err(PyValueError::new_err(format!(
+            "expected {expected} values to match the length of the view, got {len}"
+        )))
+    }
+}
@@ -0,0 +1,12 @@
+use crate::bool::Bool;
+use crate::float32::Float32;
+use crate::int32::Int32;
+use crate::numeric::numeric_column;
+use crate::uint32::UInt32;
+
+numeric_column!(Float64, f64, "float64", "float", float, [
+    Float32 => f64,
+    Int32 => f64,
+    UInt32 => f64,
+    Bool => f64,
+]);
@@ -1,9 +1,100 @@
-use numpy::PyArray1;
+use crate::error_handlers::{check_length, index_out_of_bounds};
+use crate::index::Index;
+use numpy::{PyArray1, PyUntypedArray};
+use pyo3::exceptions::PyTypeError;
 use pyo3::prelude::*;
 use pyo3::types::PySlice;
 
+/// A key which selects elements of an array view, following NumPy's
+/// indexing rules.
 #[derive(FromPyObject)]
 pub enum GetItemKey<'a> {
     Slice(&'a PySlice),
+    Index(isize),
     ArrayMask(&'a PyArray1<bool>),
+    ArrayIndices(IndexArray<'a>),
+    VecMask(Vec<bool>),
+    VecIndices(Vec<isize>),
+}
+
+impl GetItemKey<'_> {
+    /// Select the elements of `indices` which the key refers to, in the
+    /// order it refers to them.
+    pub fn select(&self, indices: &[Index]) -> PyResult<Vec<Index>> {
+        let len = indices.len();
+        match self {
+            GetItemKey::Slice(slice) => {
+                let slice = slice.indices(len as std::os::raw::c_long)?;
+                Ok((0..slice.slicelength)
+                    .map(|i| indices[(slice.start + i * slice.step) as usize])
+                    .collect())
+            }
+            GetItemKey::Index(index) => Ok(vec![indices[position(*index, len)?]]),
+            GetItemKey::ArrayMask(mask) => {
+                check_length(len, mask.len())?;
+                Ok(select_where(
+                    indices,
+                    mask.readonly().as_array().iter().copied(),
+                ))
+            }
+            GetItemKey::ArrayIndices(IndexArray(array)) => array
+                .readonly()
+                .as_array()
+                .iter()
+                .map(|&index| Ok(indices[position(index as isize, len)?]))
+                .collect(),
+            // An empty list selects nothing, as it holds no indices either.
+            GetItemKey::VecMask(mask) if mask.is_empty() => Ok(Vec::new()),
+            GetItemKey::VecMask(mask) => {
+                check_length(len, mask.len())?;
+                Ok(select_where(indices, mask.iter().copied()))
+            }
+            GetItemKey::VecIndices(vec) => vec
+                .iter()
+                .map(|&index| Ok(indices[position(index, len)?]))
+                .collect(),
+        }
+    }
+}
+
+/// An array of integers of any dtype, cast to int64 so that it can be
+/// used as indices.
+pub struct IndexArray<'a>(&'a PyArray1<i64>);
+
+impl<'source> FromPyObject<'source> for IndexArray<'source> {
+    fn extract(ob: &'source PyAny) -> PyResult<Self> {
+        if let Ok(array) = ob.extract() {
+            return Ok(Self(array));
+        }
+        let array: &PyUntypedArray = ob.downcast()?;
+        if !matches!(array.dtype().kind(), b'i' | b'u') {
+            return Err(PyTypeError::new_err(format!(
+                "expected an array of integers, got {}",
+                array.dtype()
+            )));
+        }
+        Ok(Self(array.call_method1("astype", ("int64",))?.extract()?))
+    }
+}
+
+fn select_where(indices: &[Index], mask: impl Iterator<Item = bool>) -> Vec<Index> {
+    indices
+        .iter()
+        .zip(mask)
+        .filter_map(|(&index, keep)| keep.then_some(index))
+        .collect()
+}
+
+/// Convert `index` into a position in a view of length `len`. Negative
+/// indices count back from the end.
+pub fn position(index: isize, len: usize) -> PyResult<usize> {
+    let position = if index < 0 {
+        index.checked_add_unsigned(len)
+    } else {
+        Some(index)
+    };
+    position
+        .and_then(|position| usize::try_from(position).ok())
+        .filter(|&position| position < len)
+        .ok_or_else(|| index_out_of_bounds(index, len))
 }
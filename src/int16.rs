@@ -0,0 +1,6 @@
+use crate::bool::Bool;
+use crate::numeric::numeric_column;
+
+numeric_column!(Int16, i16, "int16", "int", int, [
+    Bool => i16,
+]);
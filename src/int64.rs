@@ -0,0 +1,6 @@
+use crate::bool::Bool;
+use crate::numeric::numeric_column;
+
+numeric_column!(Int64, i64, "int64", "int", int, [
+    Bool => i64,
+]);
@@ -0,0 +1,6 @@
+use crate::bool::Bool;
+use crate::numeric::numeric_column;
+
+numeric_column!(Int8, i8, "int8", "int", int, [
+    Bool => i8,
+]);
@@ -0,0 +1,260 @@
+use std::sync::RwLockReadGuard;
+
+use numpy::PyArray1;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use crate::array_view_indices::ArrayViewIndices;
+use crate::combinations::{gather, gather_pairs, num_entities, read_all, Pairs};
+use crate::error_handlers::check_length;
+use crate::float32::Float32;
+use crate::index::Index;
+use crate::spatial_grid::read_points;
+
+/// A kd-tree holding 2D points, for finding the points nearest to
+/// another point.
+///
+/// Building the tree only sorts the points, so it is cheap enough to
+/// rebuild every frame. For a :class:`Vec2`, pass its ``x`` and ``y``.
+///
+/// Parameters:
+///     x (Float32): The x coordinates of the points.
+///     y (Float32): The y coordinates of the points.
+/// Raises:
+///     ValueError: If `x` and `y` have different lengths, or any
+///         coordinate is not finite.
+#[pyclass(module = "xecs")]
+pub struct KdTree {
+    points: Vec<(f32, f32)>,
+    /// The positions of the points, ordered so that the point in the
+    /// middle of any subtree splits the rest of it in two.
+    order: Vec<usize>,
+}
+
+#[pymethods]
+impl KdTree {
+    #[new]
+    fn new(x: PyRef<Float32>, y: PyRef<Float32>) -> PyResult<Self> {
+        let points = read_finite_points(&x, &y)?;
+        let mut order: Vec<_> = (0..points.len()).collect();
+        build(&points, &mut order, 0);
+        Ok(Self { points, order })
+    }
+    fn __len__(&self) -> usize {
+        self.points.len()
+    }
+    /// Find the `k` nearest other points to each point.
+    ///
+    /// The neighbors of each point are next to each other and sorted
+    /// by distance, so the ``i``-th point is paired with its neighbors
+    /// at positions ``i * k`` to ``(i + 1) * k``.
+    ///
+    /// Parameters:
+    ///     k (int): The number of neighbors of each point.
+    ///     indices (list[ArrayViewIndices]): The indices of each
+    ///         component of the query which the points belong to, in
+    ///         the same order as the points.
+    /// Returns:
+    ///     tuple: The indices of each component for the first and the
+    ///     second point of every pair, as in
+    ///     :meth:`SpatialGrid.pairs_within`, and the distance between
+    ///     them.
+    /// Raises:
+    ///     ValueError: If `k` is not less than the number of points, or
+    ///         `indices` do not have one index for each point.
+    fn nearest(
+        &self,
+        py: Python,
+        k: usize,
+        indices: Vec<PyRef<ArrayViewIndices>>,
+    ) -> PyResult<(Pairs, Py<PyArray1<f32>>)> {
+        let indices = read_all(&indices)?;
+        self.check_indices(&indices)?;
+        if k > self.points.len().saturating_sub(1) {
+            return Err(PyValueError::new_err(
+                "k must be less than the number of points",
+            ));
+        }
+        let mut pairs = Vec::with_capacity(self.points.len() * k);
+        let mut distances = Vec::with_capacity(self.points.len() * k);
+        for (i, &point) in self.points.iter().enumerate() {
+            for (j, distance) in self.k_nearest(point, k, Some(i)) {
+                pairs.push((i, j));
+                distances.push(distance);
+            }
+        }
+        Ok((
+            gather_pairs(&indices, &indices, pairs.len(), pairs.into_iter()),
+            PyArray1::from_vec(py, distances).to_owned(),
+        ))
+    }
+    /// Find the `k` nearest points to each of the points in `x` and `y`.
+    ///
+    /// The neighbors of each of those points are next to each other and
+    /// sorted by distance, so the neighbors of the ``i``-th one are at
+    /// positions ``i * k`` to ``(i + 1) * k``.
+    ///
+    /// Parameters:
+    ///     x (Float32): The x coordinates of the points to search from.
+    ///     y (Float32): The y coordinates of the points to search from.
+    ///     k (int): The number of neighbors of each point.
+    ///     indices (list[ArrayViewIndices]): The indices of each
+    ///         component of the query which the points in the tree
+    ///         belong to, in the same order as the points.
+    /// Returns:
+    ///     tuple[list[ArrayViewIndices], numpy.ndarray]: The indices of
+    ///     each component for every neighbor, and its distance.
+    /// Raises:
+    ///     ValueError: If `k` is more than the number of points in the
+    ///         tree, `x` and `y` have different lengths or hold a
+    ///         coordinate which is not finite, or `indices` do not have
+    ///         one index for each point.
+    fn nearest_to(
+        &self,
+        py: Python,
+        x: PyRef<Float32>,
+        y: PyRef<Float32>,
+        k: usize,
+        indices: Vec<PyRef<ArrayViewIndices>>,
+    ) -> PyResult<(Vec<ArrayViewIndices>, Py<PyArray1<f32>>)> {
+        let probes = read_finite_points(&x, &y)?;
+        let indices = read_all(&indices)?;
+        self.check_indices(&indices)?;
+        if k > self.points.len() {
+            return Err(PyValueError::new_err(
+                "k must be at most the number of points",
+            ));
+        }
+        let mut neighbors = Vec::with_capacity(probes.len() * k);
+        let mut distances = Vec::with_capacity(probes.len() * k);
+        for &probe in probes.iter() {
+            for (j, distance) in self.k_nearest(probe, k, None) {
+                neighbors.push(j);
+                distances.push(distance);
+            }
+        }
+        Ok((
+            gather(&indices, &neighbors),
+            PyArray1::from_vec(py, distances).to_owned(),
+        ))
+    }
+}
+
+impl KdTree {
+    fn check_indices(&self, indices: &[RwLockReadGuard<Vec<Index>>]) -> PyResult<()> {
+        if indices.is_empty() {
+            return Ok(());
+        }
+        check_length(self.points.len(), num_entities(indices))
+    }
+    /// Find the positions of the `k` points nearest to `point`, other
+    /// than the one at position `exclude`, along with their distances.
+    fn k_nearest(
+        &self,
+        point: (f32, f32),
+        k: usize,
+        exclude: Option<usize>,
+    ) -> impl Iterator<Item = (usize, f32)> {
+        let mut nearest = Nearest {
+            k,
+            neighbors: Vec::with_capacity(k + 1),
+        };
+        if k > 0 {
+            self.search(&self.order, 0, point, exclude, &mut nearest);
+        }
+        nearest
+            .neighbors
+            .into_iter()
+            .map(|(distance_squared, j)| (j, distance_squared.sqrt()))
+    }
+    fn search(
+        &self,
+        order: &[usize],
+        depth: usize,
+        point: (f32, f32),
+        exclude: Option<usize>,
+        nearest: &mut Nearest,
+    ) {
+        if order.is_empty() {
+            return;
+        }
+        let middle = order.len() / 2;
+        let j = order[middle];
+        let other = self.points[j];
+        if exclude != Some(j) {
+            nearest.offer(distance_squared(point, other), j);
+        }
+        let difference = coordinate(point, depth) - coordinate(other, depth);
+        let (near, far) = if difference < 0.0 {
+            (&order[..middle], &order[middle + 1..])
+        } else {
+            (&order[middle + 1..], &order[..middle])
+        };
+        self.search(near, depth + 1, point, exclude, nearest);
+        // Every point on the far side is at least `difference` away.
+        if difference * difference < nearest.worst() {
+            self.search(far, depth + 1, point, exclude, nearest);
+        }
+    }
+}
+
+/// The nearest points found so far, as squared distances and positions
+/// sorted by distance.
+struct Nearest {
+    k: usize,
+    neighbors: Vec<(f32, usize)>,
+}
+
+impl Nearest {
+    fn worst(&self) -> f32 {
+        if self.neighbors.len() < self.k {
+            f32::INFINITY
+        } else {
+            self.neighbors[self.k - 1].0
+        }
+    }
+    fn offer(&mut self, distance_squared: f32, j: usize) {
+        if distance_squared < self.worst() {
+            let position = self
+                .neighbors
+                .partition_point(|&(other, _)| other <= distance_squared);
+            self.neighbors.insert(position, (distance_squared, j));
+            self.neighbors.truncate(self.k);
+        }
+    }
+}
+
+/// Order the points in `order` into a tree, splitting on x at even
+/// depths and on y at odd ones.
+fn build(points: &[(f32, f32)], order: &mut [usize], depth: usize) {
+    if order.len() <= 1 {
+        return;
+    }
+    let middle = order.len() / 2;
+    order.select_nth_unstable_by(middle, |&a, &b| {
+        coordinate(points[a], depth).total_cmp(&coordinate(points[b], depth))
+    });
+    let (left, right) = order.split_at_mut(middle);
+    build(points, left, depth + 1);
+    build(points, &mut right[1..], depth + 1);
+}
+
+fn read_finite_points(x: &Float32, y: &Float32) -> PyResult<Vec<(f32, f32)>> {
+    let points = read_points(x, y)?;
+    if points.iter().all(|(x, y)| x.is_finite() && y.is_finite()) {
+        Ok(points)
+    } else {
+        Err(PyValueError::new_err("points must be finite"))
+    }
+}
+
+fn coordinate((x, y): (f32, f32), depth: usize) -> f32 {
+    match depth % 2 {
+        0 => x,
+        _ => y,
+    }
+}
+
+fn distance_squared((x1, y1): (f32, f32), (x2, y2): (f32, f32)) -> f32 {
+    (x2 - x1).powi(2) + (y2 - y1).powi(2)
+}
@@ -3,37 +3,67 @@ use pyo3::prelude::*;
 mod app;
 mod array_view_indices;
 mod bool;
+mod change_ticks;
+mod column;
 mod combinations;
 mod component_id;
 mod component_pool;
 mod entity_id;
 mod error_handlers;
 mod float32;
+mod float64;
 mod getitem_key;
 mod index;
+mod int16;
 mod int32;
+mod int64;
+mod int8;
+mod kd_tree;
 mod map;
+mod numeric;
 mod py_field;
 mod query;
 mod query_id;
+mod removed_reader_id;
 mod set;
+mod spatial_grid;
+mod tick;
 mod time;
+mod uint16;
 mod uint32;
+mod uint64;
+mod uint8;
 
 /// Internal Rust implementations.
 #[pymodule]
 fn xecs(_py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<float32::Float32>()?;
+    m.add_class::<float64::Float64>()?;
+    m.add_class::<int8::Int8>()?;
+    m.add_class::<int16::Int16>()?;
     m.add_class::<int32::Int32>()?;
+    m.add_class::<int64::Int64>()?;
+    m.add_class::<uint8::UInt8>()?;
+    m.add_class::<uint16::UInt16>()?;
     m.add_class::<uint32::UInt32>()?;
+    m.add_class::<uint64::UInt64>()?;
     m.add_class::<bool::Bool>()?;
     m.add_class::<app::RustApp>()?;
     m.add_class::<array_view_indices::ArrayViewIndices>()?;
     m.add_class::<array_view_indices::MultipleArrayViewIndices>()?;
+    m.add_class::<column::Column>()?;
     m.add_class::<time::Duration>()?;
     m.add_class::<time::Instant>()?;
     m.add_class::<time::Time>()?;
     m.add_class::<py_field::PyField>()?;
+    m.add_class::<spatial_grid::SpatialGrid>()?;
+    m.add_class::<kd_tree::KdTree>()?;
+    m.add_class::<combinations::PairChunks>()?;
     m.add_function(wrap_pyfunction!(combinations::product_2, m)?)?;
+    m.add_function(wrap_pyfunction!(combinations::combinations_2, m)?)?;
+    m.add_function(wrap_pyfunction!(combinations::product_with, m)?)?;
+    m.add_function(wrap_pyfunction!(combinations::product_2_chunks, m)?)?;
+    m.add_function(wrap_pyfunction!(combinations::combinations_2_chunks, m)?)?;
+    m.add_function(wrap_pyfunction!(combinations::product_with_chunks, m)?)?;
     Ok(())
 }
@@ -0,0 +1,1575 @@
+use crate::array_view_indices::ArrayViewIndices;
+use crate::bool::Bool;
+use crate::change_ticks::ChangeTicks;
+use crate::column::Column;
+use crate::error_handlers::{cannot_read, cannot_write, check_length, divided_by_zero, overflowed};
+use crate::float32::Float32;
+use crate::float64::Float64;
+use crate::getitem_key::GetItemKey;
+use crate::index::Index;
+use crate::int16::Int16;
+use crate::int32::Int32;
+use crate::int64::Int64;
+use crate::int8::Int8;
+use crate::uint16::UInt16;
+use crate::uint32::UInt32;
+use crate::uint64::UInt64;
+use crate::uint8::UInt8;
+use numpy::PyArray1;
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::pyclass::CompareOp;
+use std::fmt::Debug;
+use std::ops::{Add, Div, Mul, Rem, Sub};
+use std::sync::{Arc, RwLock};
+
+/// A value which can be stored in a numeric column.
+pub trait Element:
+    numpy::Element
+    + Copy
+    + Debug
+    + Default
+    + PartialOrd
+    + Send
+    + Sync
+    + 'static
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+{
+    /// The type sums are accumulated in, wide enough not to overflow.
+    type Sum: Copy + Default + Add<Output = Self::Sum> + IntoPy<PyObject>;
+    const KIND: Kind;
+    fn div_euclid(self, rhs: Self) -> Self;
+    fn widen(self) -> Self::Sum;
+    fn as_f64(self) -> f64;
+    fn is_nan(self) -> bool;
+    /// Apply `op`, handling integer overflow as `overflow` says. Integer
+    /// division by zero is always an error.
+    fn arith(self, op: Arith, rhs: Self, overflow: Overflow) -> PyResult<Self>;
+}
+
+/// The kinds of element, ordered like NumPy orders them when deciding
+/// if a value can be cast to another type without changing its kind.
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+pub enum Kind {
+    Unsigned,
+    Signed,
+    Float,
+}
+
+macro_rules! impl_element {
+    (float: $($t:ty),*) => {
+        $(
+            impl Element for $t {
+                type Sum = f64;
+                const KIND: Kind = Kind::Float;
+                fn div_euclid(self, rhs: Self) -> Self {
+                    <$t>::div_euclid(self, rhs)
+                }
+                fn widen(self) -> f64 {
+                    self.into()
+                }
+                fn as_f64(self) -> f64 {
+                    self.into()
+                }
+                fn is_nan(self) -> bool {
+                    <$t>::is_nan(self)
+                }
+                fn arith(self, op: Arith, rhs: Self, _overflow: Overflow) -> PyResult<Self> {
+                    Ok(op.apply(self, rhs))
+                }
+            }
+        )*
+    };
+    (int: $($t:ty),*) => {
+        $(
+            impl Element for $t {
+                type Sum = i128;
+                const KIND: Kind = if <$t>::MIN == 0 {
+                    Kind::Unsigned
+                } else {
+                    Kind::Signed
+                };
+                fn div_euclid(self, rhs: Self) -> Self {
+                    <$t>::div_euclid(self, rhs)
+                }
+                fn widen(self) -> i128 {
+                    self.into()
+                }
+                fn as_f64(self) -> f64 {
+                    self as f64
+                }
+                fn is_nan(self) -> bool {
+                    false
+                }
+                fn arith(self, op: Arith, rhs: Self, overflow: Overflow) -> PyResult<Self> {
+                    if rhs == 0 && matches!(op, Arith::Div | Arith::FloorDiv | Arith::Rem) {
+                        return Err(divided_by_zero());
+                    }
+                    let result = match overflow {
+                        Overflow::Checked => match op {
+                            Arith::Add => self.checked_add(rhs),
+                            Arith::Sub => self.checked_sub(rhs),
+                            Arith::Mul => self.checked_mul(rhs),
+                            Arith::Div => self.checked_div(rhs),
+                            Arith::FloorDiv => self.checked_div_euclid(rhs),
+                            Arith::Rem => Some(self.wrapping_rem(rhs)),
+                        },
+                        Overflow::Wrapping => Some(match op {
+                            Arith::Add => self.wrapping_add(rhs),
+                            Arith::Sub => self.wrapping_sub(rhs),
+                            Arith::Mul => self.wrapping_mul(rhs),
+                            Arith::Div => self.wrapping_div(rhs),
+                            Arith::FloorDiv => self.wrapping_div_euclid(rhs),
+                            Arith::Rem => self.wrapping_rem(rhs),
+                        }),
+                        Overflow::Saturating => Some(match op {
+                            Arith::Add => self.saturating_add(rhs),
+                            Arith::Sub => self.saturating_sub(rhs),
+                            Arith::Mul => self.saturating_mul(rhs),
+                            Arith::Div => self.saturating_div(rhs),
+                            Arith::FloorDiv => {
+                                self.checked_div_euclid(rhs).unwrap_or(<$t>::MAX)
+                            }
+                            Arith::Rem => self.wrapping_rem(rhs),
+                        }),
+                    };
+                    result.ok_or_else(|| overflowed(op.name()))
+                }
+            }
+        )*
+    };
+}
+
+impl_element!(float: f32, f64);
+impl_element!(int: i8, i16, i32, i64, u8, u16, u32, u64);
+
+/// Convert a value to another type, like `as`.
+pub trait Cast<T> {
+    fn cast(self) -> T;
+}
+
+impl<T> Cast<T> for T {
+    fn cast(self) -> T {
+        self
+    }
+}
+
+macro_rules! impl_cast {
+    (bool => $($to:ty),*) => {
+        $(
+            impl Cast<$to> for bool {
+                fn cast(self) -> $to {
+                    <$to>::from(self)
+                }
+            }
+        )*
+    };
+    ($from:ty => $($to:ty),*) => {
+        $(
+            impl Cast<$to> for $from {
+                fn cast(self) -> $to {
+                    self as $to
+                }
+            }
+        )*
+    };
+}
+
+impl_cast!(f32 => f64, i32, i64, u32);
+impl_cast!(f64 => f32, i32, i64, u32);
+impl_cast!(i32 => f32, f64, i64, u32);
+impl_cast!(i64 => f32, f64, i32, u32);
+impl_cast!(u32 => f32, f64, i32, i64);
+impl_cast!(bool => f32, f64, i8, i16, i32, i64, u8, u16, u32, u64);
+
+/// Convert a promoted value back to the type of a column. Integers which
+/// do not fit are handled as `overflow` says, floats are cast like `as`.
+pub trait Narrow<T> {
+    fn narrow(self, overflow: Overflow) -> PyResult<T>;
+}
+
+impl<T> Narrow<T> for T {
+    fn narrow(self, _overflow: Overflow) -> PyResult<T> {
+        Ok(self)
+    }
+}
+
+macro_rules! impl_narrow {
+    (f64 => $($to:ty),*) => {
+        $(
+            impl Narrow<$to> for f64 {
+                fn narrow(self, _overflow: Overflow) -> PyResult<$to> {
+                    Ok(self as $to)
+                }
+            }
+        )*
+    };
+    (i64 => $($to:ty),*) => {
+        $(
+            impl Narrow<$to> for i64 {
+                fn narrow(self, overflow: Overflow) -> PyResult<$to> {
+                    match overflow {
+                        Overflow::Checked => {
+                            <$to>::try_from(self).map_err(|_| overflowed("conversion"))
+                        }
+                        Overflow::Wrapping => Ok(self as $to),
+                        Overflow::Saturating => {
+                            Ok(self.clamp(<$to>::MIN.into(), <$to>::MAX.into()) as $to)
+                        }
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_narrow!(f64 => f32, i32, u32);
+impl_narrow!(i64 => i32, u32);
+
+/// What integer arithmetic does when a result does not fit in its type.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Overflow {
+    /// Raise an `OverflowError`.
+    Checked,
+    /// Wrap around, like NumPy.
+    Wrapping,
+    /// Clamp to the nearest value which fits.
+    Saturating,
+}
+
+impl<'source> FromPyObject<'source> for Overflow {
+    fn extract(ob: &'source PyAny) -> PyResult<Self> {
+        match ob.extract()? {
+            "checked" => Ok(Overflow::Checked),
+            "wrapping" => Ok(Overflow::Wrapping),
+            "saturating" => Ok(Overflow::Saturating),
+            _ => Err(PyValueError::new_err(
+                "overflow must be \"checked\", \"wrapping\" or \"saturating\"",
+            )),
+        }
+    }
+}
+
+impl IntoPy<PyObject> for Overflow {
+    fn into_py(self, py: Python) -> PyObject {
+        match self {
+            Overflow::Checked => "checked",
+            Overflow::Wrapping => "wrapping",
+            Overflow::Saturating => "saturating",
+        }
+        .into_py(py)
+    }
+}
+
+/// An arithmetic operator.
+#[derive(Clone, Copy)]
+pub enum Arith {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    FloorDiv,
+    Rem,
+}
+
+impl Arith {
+    fn name(self) -> &'static str {
+        match self {
+            Arith::Add => "addition",
+            Arith::Sub => "subtraction",
+            Arith::Mul => "multiplication",
+            Arith::Div => "division",
+            Arith::FloorDiv => "floor division",
+            Arith::Rem => "remainder",
+        }
+    }
+    /// Apply the operator without checking for integer overflow or
+    /// division by zero.
+    #[inline]
+    pub fn apply<T: Element>(self, a: T, b: T) -> T {
+        match self {
+            Arith::Add => a + b,
+            Arith::Sub => a - b,
+            Arith::Mul => a * b,
+            Arith::Div => a / b,
+            Arith::FloorDiv => a.div_euclid(b),
+            Arith::Rem => a % b,
+        }
+    }
+}
+
+/// Compare two values with `op`.
+#[inline]
+pub fn compare<T: Element>(op: CompareOp, a: T, b: T) -> bool {
+    match op {
+        CompareOp::Lt => a < b,
+        CompareOp::Le => a <= b,
+        CompareOp::Gt => a > b,
+        CompareOp::Ge => a >= b,
+        CompareOp::Eq => a == b,
+        CompareOp::Ne => a != b,
+    }
+}
+
+/// The right hand side of an operation on a numeric column.
+pub enum Operand<'a, T> {
+    Value(T),
+    Column(&'a Numeric<T>),
+    Array(&'a PyArray1<T>),
+    Vec(&'a [T]),
+}
+
+impl<T> Operand<'_, T> {
+    /// Get the number of values, or `None` for a single value which is
+    /// used for every element.
+    fn len(&self) -> PyResult<Option<usize>> {
+        Ok(match self {
+            Operand::Value(_) => None,
+            Operand::Column(column) => Some(column.indices.0.read().map_err(cannot_read)?.len()),
+            Operand::Array(array) => Some(array.len()),
+            Operand::Vec(vec) => Some(vec.len()),
+        })
+    }
+}
+
+/// A column of any type, so that a column which cannot be combined with
+/// another can be rejected by name rather than failing to extract.
+#[derive(FromPyObject)]
+#[allow(dead_code)]
+pub enum AnyColumn<'a> {
+    Bool(&'a PyCell<Bool>),
+    Float32(&'a PyCell<Float32>),
+    Float64(&'a PyCell<Float64>),
+    Int8(&'a PyCell<Int8>),
+    Int16(&'a PyCell<Int16>),
+    Int32(&'a PyCell<Int32>),
+    Int64(&'a PyCell<Int64>),
+    UInt8(&'a PyCell<UInt8>),
+    UInt16(&'a PyCell<UInt16>),
+    UInt32(&'a PyCell<UInt32>),
+    UInt64(&'a PyCell<UInt64>),
+}
+
+impl AnyColumn<'_> {
+    fn dtype(&self) -> &'static str {
+        match self {
+            AnyColumn::Bool(_) => "bool",
+            AnyColumn::Float32(_) => "float32",
+            AnyColumn::Float64(_) => "float64",
+            AnyColumn::Int8(_) => "int8",
+            AnyColumn::Int16(_) => "int16",
+            AnyColumn::Int32(_) => "int32",
+            AnyColumn::Int64(_) => "int64",
+            AnyColumn::UInt8(_) => "uint8",
+            AnyColumn::UInt16(_) => "uint16",
+            AnyColumn::UInt32(_) => "uint32",
+            AnyColumn::UInt64(_) => "uint64",
+        }
+    }
+    /// The error for combining a column of `dtype` with this one.
+    pub fn unsupported(&self, dtype: &str) -> PyErr {
+        PyTypeError::new_err(format!(
+            "cannot combine {dtype} and {} columns",
+            self.dtype()
+        ))
+    }
+}
+
+/// The storage and operations shared by every numeric column type.
+pub struct Numeric<T> {
+    change_ticks: ChangeTicks,
+    default: T,
+    overflow: Arc<RwLock<Overflow>>,
+    array: Arc<RwLock<Vec<T>>>,
+    indices: ArrayViewIndices,
+}
+
+impl<T: Element> Numeric<T> {
+    pub fn from_vec(vec: Vec<T>) -> Self {
+        let len = vec.len();
+        Self {
+            change_ticks: ChangeTicks::new(len),
+            default: T::default(),
+            overflow: Arc::new(RwLock::new(Overflow::Checked)),
+            array: Arc::new(RwLock::new(vec)),
+            indices: ArrayViewIndices(Arc::new(RwLock::new((0..len as Index).collect()))),
+        }
+    }
+    pub fn from_indices(
+        indices: &ArrayViewIndices,
+        default: T,
+        overflow: Overflow,
+    ) -> PyResult<Self> {
+        let capacity = indices.0.read().map_err(cannot_read)?.capacity();
+        Ok(Self {
+            change_ticks: ChangeTicks::new(capacity),
+            default,
+            overflow: Arc::new(RwLock::new(overflow)),
+            array: Arc::new(RwLock::new(vec![default; capacity])),
+            indices: ArrayViewIndices(Arc::clone(&indices.0)),
+        })
+    }
+    pub fn new_view_with_indices(&self, indices: &ArrayViewIndices) -> Self {
+        Self {
+            change_ticks: self.change_ticks.share(),
+            default: self.default,
+            overflow: Arc::clone(&self.overflow),
+            array: Arc::clone(&self.array),
+            indices: ArrayViewIndices(Arc::clone(&indices.0)),
+        }
+    }
+    pub fn getitem(&self, key: &GetItemKey) -> PyResult<Self> {
+        Ok(Self {
+            change_ticks: self.change_ticks.share(),
+            default: self.default,
+            overflow: Arc::clone(&self.overflow),
+            array: Arc::clone(&self.array),
+            indices: self.indices.select(key)?,
+        })
+    }
+    /// Replace the elements `key` selects with `rhs`, which lines up
+    /// with the selected elements, like in NumPy.
+    pub fn setitem(&self, key: &GetItemKey, rhs: &Operand<T>) -> PyResult<()> {
+        self.getitem(key)?.update_with(rhs, |_, b| b)
+    }
+    pub fn column(&self) -> Column {
+        Column::new(
+            self.change_ticks.share(),
+            Arc::clone(&self.array),
+            self.default,
+        )
+    }
+    pub fn overflow(&self) -> PyResult<Overflow> {
+        Ok(*self.overflow.read().map_err(cannot_read)?)
+    }
+    pub fn set_overflow(&self, overflow: Overflow) -> PyResult<()> {
+        *self.overflow.write().map_err(cannot_write)? = overflow;
+        Ok(())
+    }
+    pub fn len(&self) -> PyResult<usize> {
+        Ok(self.indices.0.read().map_err(cannot_read)?.len())
+    }
+    pub fn get(&self, index: isize) -> PyResult<T> {
+        let index = self.indices.get(index)?;
+        let array = self.array.read().map_err(cannot_read)?;
+        Ok(array[index as usize])
+    }
+    /// Copy the elements in the view.
+    pub fn values(&self) -> PyResult<Vec<T>> {
+        self.map(|value| value)
+    }
+    /// Apply `f` to every element in the view.
+    pub fn map<U>(&self, f: impl Fn(T) -> U) -> PyResult<Vec<U>> {
+        self.try_map(|a| Ok(f(a)))
+    }
+    /// Like [`Numeric::map`], but stop at the first error.
+    pub fn try_map<U>(&self, f: impl Fn(T) -> PyResult<U>) -> PyResult<Vec<U>> {
+        let array = self.array.read().map_err(cannot_read)?;
+        let indices = self.indices.0.read().map_err(cannot_read)?;
+        gather(&array, &indices).map(f).collect()
+    }
+    /// Apply `f` to every element in the view and the matching element
+    /// of `rhs`.
+    pub fn zip<U>(&self, rhs: &Operand<T>, f: impl Fn(T, T) -> U) -> PyResult<Vec<U>> {
+        self.try_zip(rhs, |a, b| Ok(f(a, b)))
+    }
+    /// Like [`Numeric::zip`], but stop at the first error.
+    pub fn try_zip<U>(
+        &self,
+        rhs: &Operand<T>,
+        f: impl Fn(T, T) -> PyResult<U>,
+    ) -> PyResult<Vec<U>> {
+        self.check_length(rhs)?;
+        let array = self.array.read().map_err(cannot_read)?;
+        let indices = self.indices.0.read().map_err(cannot_read)?;
+        let lhs = gather(&array, &indices);
+        match *rhs {
+            Operand::Value(other) => lhs.map(|value| f(value, other)).collect(),
+            Operand::Column(other) => {
+                let other_indices = other.indices.0.read().map_err(cannot_read)?;
+                if Arc::ptr_eq(&self.array, &other.array) {
+                    lhs.zip(gather(&array, &other_indices))
+                        .map(|(a, b)| f(a, b))
+                        .collect()
+                } else {
+                    let other_array = other.array.read().map_err(cannot_read)?;
+                    lhs.zip(gather(&other_array, &other_indices))
+                        .map(|(a, b)| f(a, b))
+                        .collect()
+                }
+            }
+            Operand::Array(py_array) => lhs
+                .zip(py_array.readonly().as_array())
+                .map(|(a, &b)| f(a, b))
+                .collect(),
+            Operand::Vec(vec) => lhs.zip(vec).map(|(a, &b)| f(a, b)).collect(),
+        }
+    }
+    /// Apply `f` to every element in the view and the matching value.
+    pub fn zip_values<I, U>(&self, values: I, f: impl Fn(T, I::Item) -> U) -> PyResult<Vec<U>>
+    where
+        I: IntoIterator,
+        I::IntoIter: ExactSizeIterator,
+    {
+        self.try_zip_values(values, |a, b| Ok(f(a, b)))
+    }
+    /// Like [`Numeric::zip_values`], but stop at the first error.
+    pub fn try_zip_values<I, U>(
+        &self,
+        values: I,
+        f: impl Fn(T, I::Item) -> PyResult<U>,
+    ) -> PyResult<Vec<U>>
+    where
+        I: IntoIterator,
+        I::IntoIter: ExactSizeIterator,
+    {
+        let values = values.into_iter();
+        let array = self.array.read().map_err(cannot_read)?;
+        let indices = self.indices.0.read().map_err(cannot_read)?;
+        check_length(indices.len(), values.len())?;
+        gather(&array, &indices)
+            .zip(values)
+            .map(|(a, b)| f(a, b))
+            .collect()
+    }
+    /// Combine every element in the view with the matching element of
+    /// `rhs`, handling integer overflow as the column says.
+    pub fn arith(&self, rhs: &Operand<T>, op: Arith) -> PyResult<Vec<T>> {
+        let overflow = self.overflow()?;
+        self.try_zip(rhs, |a, b| a.arith(op, b, overflow))
+    }
+    /// Like [`Numeric::arith`], but the result replaces the view. If any
+    /// element fails, none are replaced.
+    pub fn arith_in_place(&self, rhs: &Operand<T>, op: Arith) -> PyResult<()> {
+        if T::KIND == Kind::Float {
+            // Float arithmetic cannot fail, so write the results directly.
+            return self.update_with(rhs, |a, b| op.apply(a, b));
+        }
+        let overflow = self.overflow()?;
+        let values = self.try_zip(rhs, |a, b| a.arith(op, b, overflow))?;
+        self.update_values(values, |_, b| b)
+    }
+    /// Replace every element in the view with the result of `f`.
+    pub fn update(&self, f: impl Fn(T) -> T) -> PyResult<()> {
+        let mut array = self.array.write().map_err(cannot_write)?;
+        let indices = self.indices.0.read().map_err(cannot_read)?;
+        for &index in indices.iter() {
+            let a = unsafe { array.get_unchecked_mut(index as usize) };
+            *a = f(*a);
+        }
+        self.change_ticks.mark(&indices)
+    }
+    /// Like [`Numeric::update`], but if `f` fails for any element, no
+    /// element is replaced.
+    pub fn try_update(&self, f: impl Fn(T) -> PyResult<T>) -> PyResult<()> {
+        let values = self.try_map(f)?;
+        self.update_values(values, |_, b| b)
+    }
+    /// Like [`Numeric::update_values`], but if `f` fails for any element,
+    /// no element is replaced.
+    pub fn try_update_values<I>(
+        &self,
+        values: I,
+        f: impl Fn(T, I::Item) -> PyResult<T>,
+    ) -> PyResult<()>
+    where
+        I: IntoIterator,
+        I::IntoIter: ExactSizeIterator,
+    {
+        let values = self.try_zip_values(values, f)?;
+        self.update_values(values, |_, b| b)
+    }
+    /// Replace every element in the view with the result of `f` applied
+    /// to it and the matching element of `rhs`.
+    pub fn update_with(&self, rhs: &Operand<T>, f: impl Fn(T, T) -> T) -> PyResult<()> {
+        self.check_length(rhs)?;
+        let mut array = self.array.write().map_err(cannot_write)?;
+        let indices = self.indices.0.read().map_err(cannot_read)?;
+        match *rhs {
+            Operand::Value(other) => {
+                apply(&mut array, &indices, std::iter::repeat(other), f);
+            }
+            Operand::Column(other) => {
+                let other_indices = other.indices.0.read().map_err(cannot_read)?;
+                if Arc::ptr_eq(&self.array, &other.array) {
+                    let values: Vec<_> = gather(&array, &other_indices).collect();
+                    apply(&mut array, &indices, values, f);
+                } else {
+                    let other_array = other.array.read().map_err(cannot_read)?;
+                    let values = gather(&other_array, &other_indices);
+                    apply(&mut array, &indices, values, f);
+                }
+            }
+            Operand::Array(py_array) => {
+                let values = py_array.readonly();
+                let values = values.as_array().into_iter().copied();
+                apply(&mut array, &indices, values, f);
+            }
+            Operand::Vec(vec) => {
+                apply(&mut array, &indices, vec.iter().copied(), f);
+            }
+        }
+        self.change_ticks.mark(&indices)
+    }
+    /// Replace every element in the view with the result of `f` applied
+    /// to it and the matching value.
+    pub fn update_values<I>(&self, values: I, f: impl Fn(T, I::Item) -> T) -> PyResult<()>
+    where
+        I: IntoIterator,
+        I::IntoIter: ExactSizeIterator,
+    {
+        let values = values.into_iter();
+        let mut array = self.array.write().map_err(cannot_write)?;
+        let indices = self.indices.0.read().map_err(cannot_read)?;
+        check_length(indices.len(), values.len())?;
+        apply(&mut array, &indices, values, f);
+        self.change_ticks.mark(&indices)
+    }
+    /// Check that `rhs` has a value for every element in the view.
+    fn check_length(&self, rhs: &Operand<T>) -> PyResult<()> {
+        match rhs.len()? {
+            Some(len) => check_length(self.len()?, len),
+            None => Ok(()),
+        }
+    }
+    /// Add up the elements in the view.
+    pub fn sum(&self) -> PyResult<T::Sum> {
+        let array = self.array.read().map_err(cannot_read)?;
+        let indices = self.indices.0.read().map_err(cannot_read)?;
+        let sum =
+            gather(&array, &indices).fold(T::Sum::default(), |sum, value| sum + value.widen());
+        Ok(sum)
+    }
+    /// Average the elements in the view. This is NaN if the view is empty.
+    pub fn mean(&self) -> PyResult<f64> {
+        let array = self.array.read().map_err(cannot_read)?;
+        let indices = self.indices.0.read().map_err(cannot_read)?;
+        let sum: f64 = gather(&array, &indices).map(T::as_f64).sum();
+        Ok(sum / indices.len() as f64)
+    }
+    /// Check if any element in the view is non-zero.
+    pub fn any(&self) -> PyResult<bool> {
+        let array = self.array.read().map_err(cannot_read)?;
+        let indices = self.indices.0.read().map_err(cannot_read)?;
+        let result = gather(&array, &indices).any(|value| value != T::default());
+        Ok(result)
+    }
+    /// Check if every element in the view is non-zero.
+    pub fn all(&self) -> PyResult<bool> {
+        let array = self.array.read().map_err(cannot_read)?;
+        let indices = self.indices.0.read().map_err(cannot_read)?;
+        let result = gather(&array, &indices).all(|value| value != T::default());
+        Ok(result)
+    }
+    /// Find the smallest element in the view and its position.
+    pub fn min(&self) -> PyResult<(usize, T)> {
+        self.extreme(|a, b| a < b)
+    }
+    /// Find the largest element in the view and its position.
+    pub fn max(&self) -> PyResult<(usize, T)> {
+        self.extreme(|a, b| a > b)
+    }
+    /// Find the first element in the view which no other element is
+    /// `better` than. Like NumPy, the first NaN wins if there is one.
+    fn extreme(&self, better: impl Fn(T, T) -> bool) -> PyResult<(usize, T)> {
+        let array = self.array.read().map_err(cannot_read)?;
+        let indices = self.indices.0.read().map_err(cannot_read)?;
+        let mut values = gather(&array, &indices).enumerate();
+        let mut best = values.next().ok_or_else(|| {
+            PyValueError::new_err("attempt to reduce an empty view, which has no identity")
+        })?;
+        if best.1.is_nan() {
+            return Ok(best);
+        }
+        for (position, value) in values {
+            if value.is_nan() {
+                return Ok((position, value));
+            }
+            if better(value, best.1) {
+                best = (position, value);
+            }
+        }
+        Ok(best)
+    }
+}
+
+/// Limit `value` to the range `[min, max]`. Unlike `f32::clamp`, this
+/// does not panic if `min > max` or either limit is NaN.
+pub fn clamp<T: Element>(value: T, min: T, max: T) -> T {
+    if value < min {
+        min
+    } else if value > max {
+        max
+    } else {
+        value
+    }
+}
+
+fn gather<'a, T: Copy>(array: &'a [T], indices: &'a [Index]) -> impl Iterator<Item = T> + 'a {
+    indices
+        .iter()
+        .map(|&index| unsafe { *array.get_unchecked(index as usize) })
+}
+
+fn apply<T: Copy, R>(
+    array: &mut [T],
+    indices: &[Index],
+    values: impl IntoIterator<Item = R>,
+    f: impl Fn(T, R) -> T,
+) {
+    for (&index, value) in indices.iter().zip(values) {
+        let a = unsafe { array.get_unchecked_mut(index as usize) };
+        *a = f(*a, value);
+    }
+}
+
+/// Define the pyclass for a numeric column.
+///
+/// Every element type gets the same core methods, operators and
+/// semantics, plus the extra ones for its kind: `float`, `int` or `uint`.
+/// The final argument lists the other column types the operators accept,
+/// each with the type both sides are converted to before being combined.
+macro_rules! numeric_column {
+    ($name:ident, $t:ty, $dtype:literal, $py_type:literal, float, $promote:tt) => {
+        #[derive(pyo3::FromPyObject)]
+        enum PowRhs<'a> {
+            I32(i32),
+            Value($t),
+            Column(pyo3::PyRef<'a, $name>),
+            Int32(pyo3::PyRef<'a, $crate::int32::Int32>),
+            Array(&'a numpy::PyArray1<$t>),
+            ArrayI32(&'a numpy::PyArray1<i32>),
+            Unsupported($crate::numeric::AnyColumn<'a>),
+            Mismatched(&'a numpy::PyUntypedArray),
+            VecI32(Vec<i32>),
+            Vec(Vec<$t>),
+        }
+
+        impl PowRhs<'_> {
+            fn pow(&self, lhs: &$crate::numeric::Numeric<$t>) -> pyo3::PyResult<Vec<$t>> {
+                self.check()?;
+                match self {
+                    PowRhs::I32(exponent) => lhs.map(|a| a.powi(*exponent)),
+                    PowRhs::Int32(column) => lhs.zip_values(column.values()?, <$t>::powi),
+                    PowRhs::ArrayI32(array) => {
+                        let exponents = array.readonly();
+                        lhs.zip_values(exponents.as_array().iter().copied(), <$t>::powi)
+                    }
+                    PowRhs::VecI32(exponents) => {
+                        lhs.zip_values(exponents.iter().copied(), <$t>::powi)
+                    }
+                    _ => lhs.zip(&self.operand(), <$t>::powf),
+                }
+            }
+            fn ipow(&self, lhs: &$crate::numeric::Numeric<$t>) -> pyo3::PyResult<()> {
+                self.check()?;
+                match self {
+                    PowRhs::I32(exponent) => lhs.update(|a| a.powi(*exponent)),
+                    PowRhs::Int32(column) => lhs.update_values(column.values()?, <$t>::powi),
+                    PowRhs::ArrayI32(array) => {
+                        let exponents = array.readonly();
+                        lhs.update_values(exponents.as_array().iter().copied(), <$t>::powi)
+                    }
+                    PowRhs::VecI32(exponents) => {
+                        lhs.update_values(exponents.iter().copied(), <$t>::powi)
+                    }
+                    _ => lhs.update_with(&self.operand(), <$t>::powf),
+                }
+            }
+            fn operand(&self) -> $crate::numeric::Operand<'_, $t> {
+                use $crate::numeric::Operand;
+                match self {
+                    PowRhs::Value(value) => Operand::Value(*value),
+                    PowRhs::Column(column) => Operand::Column(&column.0),
+                    PowRhs::Array(array) => Operand::Array(array),
+                    PowRhs::Vec(vec) => Operand::Vec(vec),
+                    _ => unreachable!(),
+                }
+            }
+            /// Reject exponents which are not of this type or int32.
+            fn check(&self) -> pyo3::PyResult<()> {
+                match self {
+                    PowRhs::Unsupported(column) => Err(column.unsupported($dtype)),
+                    PowRhs::Mismatched(array) => {
+                        Err(pyo3::exceptions::PyTypeError::new_err(format!(
+                            concat!("expected an array of ", $dtype, " or int32 exponents, got {}"),
+                            array.dtype()
+                        )))
+                    }
+                    _ => Ok(()),
+                }
+            }
+        }
+
+        $crate::numeric::numeric_column!(@column $name, $t, $dtype, $py_type, $promote, {
+            fn __neg__(&self, py: Python) -> PyResult<Py<PyArray1<$t>>> {
+                Ok(PyArray1::from_vec(py, self.0.map(|a| -a)?).into_py(py))
+            }
+            /// Negate the elements in-place.
+            fn neg_in_place(&mut self) -> PyResult<()> {
+                self.0.update(|a| -a)
+            }
+            fn __abs__(&self, py: Python) -> PyResult<Py<PyArray1<$t>>> {
+                Ok(PyArray1::from_vec(py, self.0.map(<$t>::abs)?).into_py(py))
+            }
+            /// Take the absolute value of the elements in-place.
+            fn abs_in_place(&mut self) -> PyResult<()> {
+                self.0.update(<$t>::abs)
+            }
+            #[pyo3(signature = (ndigits = None))]
+            fn __round__(&self, py: Python, ndigits: Option<i32>) -> PyResult<Py<PyArray1<$t>>> {
+                let round = round(ndigits);
+                Ok(PyArray1::from_vec(py, self.0.map(round)?).into_py(py))
+            }
+            /// Round the elements in-place, with halves rounded to even.
+            ///
+            /// Parameters:
+            ///     ndigits (int | None): The number of decimal places to
+            ///         round to.
+            #[pyo3(signature = (ndigits = None))]
+            fn round_in_place(&mut self, ndigits: Option<i32>) -> PyResult<()> {
+                self.0.update(round(ndigits))
+            }
+            /// Compute the square roots of the elements.
+            ///
+            /// Returns:
+            ///     numpy.ndarray: The square roots.
+            fn sqrt(&self, py: Python) -> PyResult<Py<PyArray1<$t>>> {
+                Ok(PyArray1::from_vec(py, self.0.map(<$t>::sqrt)?).into_py(py))
+            }
+            /// Replace the elements with their square roots.
+            fn sqrt_in_place(&mut self) -> PyResult<()> {
+                self.0.update(<$t>::sqrt)
+            }
+            /// Compute the exponentials of the elements.
+            ///
+            /// Returns:
+            ///     numpy.ndarray: The exponentials.
+            fn exp(&self, py: Python) -> PyResult<Py<PyArray1<$t>>> {
+                Ok(PyArray1::from_vec(py, self.0.map(<$t>::exp)?).into_py(py))
+            }
+            /// Replace the elements with their exponentials.
+            fn exp_in_place(&mut self) -> PyResult<()> {
+                self.0.update(<$t>::exp)
+            }
+            /// Compute the natural logarithms of the elements.
+            ///
+            /// Returns:
+            ///     numpy.ndarray: The natural logarithms.
+            fn log(&self, py: Python) -> PyResult<Py<PyArray1<$t>>> {
+                Ok(PyArray1::from_vec(py, self.0.map(<$t>::ln)?).into_py(py))
+            }
+            /// Replace the elements with their natural logarithms.
+            fn log_in_place(&mut self) -> PyResult<()> {
+                self.0.update(<$t>::ln)
+            }
+            /// Compute the sines of the elements, which are in radians.
+            ///
+            /// Returns:
+            ///     numpy.ndarray: The sines.
+            fn sin(&self, py: Python) -> PyResult<Py<PyArray1<$t>>> {
+                Ok(PyArray1::from_vec(py, self.0.map(<$t>::sin)?).into_py(py))
+            }
+            /// Replace the elements, which are in radians, with their sines.
+            fn sin_in_place(&mut self) -> PyResult<()> {
+                self.0.update(<$t>::sin)
+            }
+            /// Compute the cosines of the elements, which are in radians.
+            ///
+            /// Returns:
+            ///     numpy.ndarray: The cosines.
+            fn cos(&self, py: Python) -> PyResult<Py<PyArray1<$t>>> {
+                Ok(PyArray1::from_vec(py, self.0.map(<$t>::cos)?).into_py(py))
+            }
+            /// Replace the elements, which are in radians, with their cosines.
+            fn cos_in_place(&mut self) -> PyResult<()> {
+                self.0.update(<$t>::cos)
+            }
+            /// Compute the four quadrant arctangents of the elements and
+            /// `other`, like ``numpy.arctan2(self, other)``.
+            ///
+            /// Parameters:
+            #[doc = concat!("    other (", $py_type, " | list[", $py_type, "]): The x coordinates.")]
+            /// Returns:
+            ///     numpy.ndarray: The angles in radians, in the range
+            ///         ``[-pi, pi]``.
+            fn atan2(&self, py: Python, other: Rhs) -> PyResult<Py<PyArray1<$t>>> {
+                let result = self.0.zip(&other.operand()?, <$t>::atan2)?;
+                Ok(PyArray1::from_vec(py, result).into_py(py))
+            }
+            /// Replace the elements with the four quadrant arctangents of
+            /// the elements and `other`.
+            ///
+            /// Parameters:
+            #[doc = concat!("    other (", $py_type, " | list[", $py_type, "]): The x coordinates.")]
+            fn atan2_in_place(&mut self, other: Rhs) -> PyResult<()> {
+                self.0.update_with(&other.operand()?, <$t>::atan2)
+            }
+            /// Round the elements down.
+            ///
+            /// Returns:
+            ///     numpy.ndarray: The rounded elements.
+            fn floor(&self, py: Python) -> PyResult<Py<PyArray1<$t>>> {
+                Ok(PyArray1::from_vec(py, self.0.map(<$t>::floor)?).into_py(py))
+            }
+            /// Round the elements down in-place.
+            fn floor_in_place(&mut self) -> PyResult<()> {
+                self.0.update(<$t>::floor)
+            }
+            /// Round the elements up.
+            ///
+            /// Returns:
+            ///     numpy.ndarray: The rounded elements.
+            fn ceil(&self, py: Python) -> PyResult<Py<PyArray1<$t>>> {
+                Ok(PyArray1::from_vec(py, self.0.map(<$t>::ceil)?).into_py(py))
+            }
+            /// Round the elements up in-place.
+            fn ceil_in_place(&mut self) -> PyResult<()> {
+                self.0.update(<$t>::ceil)
+            }
+        });
+
+        /// Round half to even at `ndigits` decimal places, like NumPy.
+        fn round(ndigits: Option<i32>) -> impl Fn($t) -> $t {
+            let scale = <$t>::powi(10.0, ndigits.unwrap_or(0));
+            move |a| (a * scale).round_ties_even() / scale
+        }
+    };
+    ($name:ident, $t:ty, $dtype:literal, $py_type:literal, int, $promote:tt) => {
+        $crate::numeric::numeric_column!(@integer $name, $t, $dtype, $py_type, $promote, {
+            fn __neg__(&self, py: Python) -> PyResult<Py<PyArray1<$t>>> {
+                let result = self.0.try_map(negate(self.0.overflow()?))?;
+                Ok(PyArray1::from_vec(py, result).into_py(py))
+            }
+            /// Negate the elements in-place.
+            fn neg_in_place(&mut self) -> PyResult<()> {
+                self.0.try_update(negate(self.0.overflow()?))
+            }
+            fn __abs__(&self, py: Python) -> PyResult<Py<PyArray1<$t>>> {
+                let result = self.0.try_map(absolute(self.0.overflow()?))?;
+                Ok(PyArray1::from_vec(py, result).into_py(py))
+            }
+            /// Take the absolute value of the elements in-place.
+            fn abs_in_place(&mut self) -> PyResult<()> {
+                self.0.try_update(absolute(self.0.overflow()?))
+            }
+        });
+
+        /// Negate, handling overflow of the minimum value as `overflow` says.
+        fn negate(
+            overflow: $crate::numeric::Overflow,
+        ) -> impl Fn($t) -> pyo3::PyResult<$t> {
+            use $crate::numeric::Overflow;
+            move |a| match overflow {
+                Overflow::Checked => a
+                    .checked_neg()
+                    .ok_or_else(|| $crate::error_handlers::overflowed("negation")),
+                Overflow::Wrapping => Ok(a.wrapping_neg()),
+                Overflow::Saturating => Ok(a.saturating_neg()),
+            }
+        }
+
+        /// Take the absolute value, handling overflow of the minimum value
+        /// as `overflow` says.
+        fn absolute(
+            overflow: $crate::numeric::Overflow,
+        ) -> impl Fn($t) -> pyo3::PyResult<$t> {
+            use $crate::numeric::Overflow;
+            move |a| match overflow {
+                Overflow::Checked => a
+                    .checked_abs()
+                    .ok_or_else(|| $crate::error_handlers::overflowed("absolute value")),
+                Overflow::Wrapping => Ok(a.wrapping_abs()),
+                Overflow::Saturating => Ok(a.saturating_abs()),
+            }
+        }
+    };
+    ($name:ident, $t:ty, $dtype:literal, $py_type:literal, uint, $promote:tt) => {
+        $crate::numeric::numeric_column!(@integer $name, $t, $dtype, $py_type, $promote, {});
+    };
+    (
+        @integer $name:ident, $t:ty, $dtype:literal, $py_type:literal, $promote:tt,
+        { $($methods:tt)* }
+    ) => {
+        #[derive(pyo3::FromPyObject)]
+        enum PowRhs<'a> {
+            U32(u32),
+            #[allow(dead_code)]
+            OutOfRange(&'a pyo3::types::PyLong),
+            Column(pyo3::PyRef<'a, $name>),
+            Array(&'a numpy::PyArray1<$t>),
+            ArrayU32(&'a numpy::PyArray1<u32>),
+            Unsupported($crate::numeric::AnyColumn<'a>),
+            Mismatched(&'a numpy::PyUntypedArray),
+            VecU32(Vec<u32>),
+        }
+
+        impl PowRhs<'_> {
+            fn pow(&self, lhs: &$crate::numeric::Numeric<$t>) -> pyo3::PyResult<Vec<$t>> {
+                self.check()?;
+                let pow = power(lhs.overflow()?);
+                match self {
+                    PowRhs::U32(exponent) => lhs.try_map(|a| pow(a, *exponent)),
+                    PowRhs::ArrayU32(array) => {
+                        let exponents = array.readonly();
+                        lhs.try_zip_values(exponents.as_array().iter().copied(), pow)
+                    }
+                    PowRhs::VecU32(exponents) => {
+                        lhs.try_zip_values(exponents.iter().copied(), pow)
+                    }
+                    _ => lhs.try_zip_values(self.exponents()?, pow),
+                }
+            }
+            fn ipow(&self, lhs: &$crate::numeric::Numeric<$t>) -> pyo3::PyResult<()> {
+                self.check()?;
+                let pow = power(lhs.overflow()?);
+                match self {
+                    PowRhs::U32(exponent) => lhs.try_update(|a| pow(a, *exponent)),
+                    PowRhs::ArrayU32(array) => {
+                        let exponents = array.readonly();
+                        lhs.try_update_values(exponents.as_array().iter().copied(), pow)
+                    }
+                    PowRhs::VecU32(exponents) => {
+                        lhs.try_update_values(exponents.iter().copied(), pow)
+                    }
+                    _ => lhs.try_update_values(self.exponents()?, pow),
+                }
+            }
+            /// Convert exponents of the column's own type, which may be
+            /// out of range.
+            fn exponents(&self) -> pyo3::PyResult<Vec<u32>> {
+                let exponents = match self {
+                    PowRhs::Column(column) => column.0.values()?,
+                    PowRhs::Array(array) => array.to_vec()?,
+                    _ => unreachable!(),
+                };
+                exponents
+                    .into_iter()
+                    .map(u32::try_from)
+                    .collect::<Result<_, _>>()
+                    .map_err(|_| exponent_out_of_range())
+            }
+            /// Reject exponents which are not of this type or uint32.
+            fn check(&self) -> pyo3::PyResult<()> {
+                match self {
+                    PowRhs::OutOfRange(_) => Err(exponent_out_of_range()),
+                    PowRhs::Unsupported(column) => Err(column.unsupported($dtype)),
+                    PowRhs::Mismatched(array) => {
+                        Err(pyo3::exceptions::PyTypeError::new_err(format!(
+                            concat!("expected an array of ", $dtype, " or uint32 exponents, got {}"),
+                            array.dtype()
+                        )))
+                    }
+                    _ => Ok(()),
+                }
+            }
+        }
+
+        fn exponent_out_of_range() -> pyo3::PyErr {
+            pyo3::exceptions::PyValueError::new_err("exponents must be between 0 and 4294967295")
+        }
+
+        /// Raise to a power, handling overflow as `overflow` says.
+        fn power(
+            overflow: $crate::numeric::Overflow,
+        ) -> impl Fn($t, u32) -> pyo3::PyResult<$t> {
+            use $crate::numeric::Overflow;
+            move |a, exponent| match overflow {
+                Overflow::Checked => a
+                    .checked_pow(exponent)
+                    .ok_or_else(|| $crate::error_handlers::overflowed("power")),
+                Overflow::Wrapping => Ok(a.wrapping_pow(exponent)),
+                Overflow::Saturating => Ok(a.saturating_pow(exponent)),
+            }
+        }
+
+        $crate::numeric::numeric_column!(@column $name, $t, $dtype, $py_type, $promote, {
+            #[doc = concat!("Set what arithmetic does when a result does not fit in ", $dtype, ".")]
+            ///
+            /// This applies to every view of the same array. Division by
+            /// zero always raises :class:`ZeroDivisionError`.
+            ///
+            /// Parameters:
+            ///     mode (str): ``"checked"`` raises :class:`OverflowError`
+            ///         and leaves the elements unchanged, ``"wrapping"``
+            ///         wraps around like NumPy and ``"saturating"`` clamps
+            ///         to the nearest value which fits. The default is
+            ///         ``"checked"``.
+            fn set_overflow(&mut self, mode: $crate::numeric::Overflow) -> PyResult<()> {
+                self.0.set_overflow(mode)
+            }
+            /// Get what arithmetic does when a result does not fit.
+            ///
+            /// Returns:
+            ///     str: ``"checked"``, ``"wrapping"`` or ``"saturating"``.
+            fn overflow(&self) -> PyResult<$crate::numeric::Overflow> {
+                self.0.overflow()
+            }
+            fn __and__(&self, py: Python, rhs: Rhs) -> PyResult<Py<PyArray1<$t>>> {
+                let result = self.0.zip(&rhs.operand()?, |a, b| a & b)?;
+                Ok(PyArray1::from_vec(py, result).into_py(py))
+            }
+            fn __iand__(&mut self, rhs: Rhs) -> PyResult<()> {
+                self.0.update_with(&rhs.operand()?, |a, b| a & b)
+            }
+            fn __or__(&self, py: Python, rhs: Rhs) -> PyResult<Py<PyArray1<$t>>> {
+                let result = self.0.zip(&rhs.operand()?, |a, b| a | b)?;
+                Ok(PyArray1::from_vec(py, result).into_py(py))
+            }
+            fn __ior__(&mut self, rhs: Rhs) -> PyResult<()> {
+                self.0.update_with(&rhs.operand()?, |a, b| a | b)
+            }
+            fn __xor__(&self, py: Python, rhs: Rhs) -> PyResult<Py<PyArray1<$t>>> {
+                let result = self.0.zip(&rhs.operand()?, |a, b| a ^ b)?;
+                Ok(PyArray1::from_vec(py, result).into_py(py))
+            }
+            fn __ixor__(&mut self, rhs: Rhs) -> PyResult<()> {
+                self.0.update_with(&rhs.operand()?, |a, b| a ^ b)
+            }
+            fn __lshift__(&self, py: Python, rhs: Rhs) -> PyResult<Py<PyArray1<$t>>> {
+                let result = self.0.zip(&rhs.operand()?, shl)?;
+                Ok(PyArray1::from_vec(py, result).into_py(py))
+            }
+            fn __ilshift__(&mut self, rhs: Rhs) -> PyResult<()> {
+                self.0.update_with(&rhs.operand()?, shl)
+            }
+            fn __rshift__(&self, py: Python, rhs: Rhs) -> PyResult<Py<PyArray1<$t>>> {
+                let result = self.0.zip(&rhs.operand()?, shr)?;
+                Ok(PyArray1::from_vec(py, result).into_py(py))
+            }
+            fn __irshift__(&mut self, rhs: Rhs) -> PyResult<()> {
+                self.0.update_with(&rhs.operand()?, shr)
+            }
+            fn __invert__(&self, py: Python) -> PyResult<Py<PyArray1<$t>>> {
+                Ok(PyArray1::from_vec(py, self.0.map(|a| !a)?).into_py(py))
+            }
+            /// Flip every bit of the elements in-place.
+            fn invert_in_place(&mut self) -> PyResult<()> {
+                self.0.update(|a| !a)
+            }
+            $($methods)*
+        });
+
+        /// Shift left like NumPy, which gives zero if `b` is out of range.
+        fn shl(a: $t, b: $t) -> $t {
+            match u32::try_from(b) {
+                Ok(b) if b < <$t>::BITS => a << b,
+                _ => 0,
+            }
+        }
+
+        /// Shift right like NumPy, which fills with the sign bit if `b` is
+        /// out of range.
+        fn shr(a: $t, b: $t) -> $t {
+            match u32::try_from(b) {
+                Ok(b) if b < <$t>::BITS => a >> b,
+                _ if a < <$t>::default() => !0,
+                _ => 0,
+            }
+        }
+    };
+    (
+        @column $name:ident, $t:ty, $dtype:literal, $py_type:literal,
+        [$($other:ident => $promoted:ty),* $(,)?],
+        { $($methods:tt)* }
+    ) => {
+        use $crate::array_view_indices::ArrayViewIndices;
+        use $crate::column::Column;
+        use $crate::getitem_key::GetItemKey;
+        use $crate::numeric::{compare, Arith, Cast, Element, Narrow, Numeric, Operand};
+        use numpy::PyArray1;
+        use pyo3::exceptions::PyTypeError;
+        use pyo3::prelude::*;
+        use pyo3::pyclass::CompareOp;
+
+        #[derive(FromPyObject)]
+        enum Rhs<'a> {
+            Value($t),
+            OutOfRange(&'a pyo3::types::PyLong),
+            Column(PyRef<'a, $name>),
+            Array(&'a PyArray1<$t>),
+            Other(Other<'a>),
+            Unsupported($crate::numeric::AnyColumn<'a>),
+            // Tried before Vec, so that arrays of another dtype are
+            // rejected instead of being converted element by element.
+            Mismatched(&'a numpy::PyUntypedArray),
+            Vec(Vec<$t>),
+        }
+
+        impl Rhs<'_> {
+            fn operand(&self) -> PyResult<Operand<'_, $t>> {
+                match self {
+                    Rhs::Value(value) => Ok(Operand::Value(*value)),
+                    Rhs::Column(column) => Ok(Operand::Column(&column.0)),
+                    Rhs::Array(array) => Ok(Operand::Array(array)),
+                    Rhs::Vec(vec) => Ok(Operand::Vec(vec)),
+                    Rhs::OutOfRange(value) => Err(out_of_range(value)),
+                    Rhs::Other(_) | Rhs::Unsupported(_) => Err(PyTypeError::new_err(concat!(
+                        "expected ",
+                        $dtype,
+                        " values, not a column of another type"
+                    ))),
+                    Rhs::Mismatched(array) => Err(PyTypeError::new_err(format!(
+                        concat!("expected an array of ", $dtype, " values, got {}"),
+                        array.dtype()
+                    ))),
+                }
+            }
+            fn arith(
+                &self,
+                py: Python,
+                lhs: &Numeric<$t>,
+                op: Arith,
+            ) -> PyResult<PyObject> {
+                match self {
+                    Rhs::Other(other) => other.arith(py, lhs, op),
+                    Rhs::Unsupported(column) => Err(column.unsupported($dtype)),
+                    _ => {
+                        let result = lhs.arith(&self.operand()?, op)?;
+                        Ok(PyArray1::from_vec(py, result).into_py(py))
+                    }
+                }
+            }
+            fn arith_in_place(&self, lhs: &Numeric<$t>, op: Arith) -> PyResult<()> {
+                match self {
+                    Rhs::Other(other) => other.arith_in_place(lhs, op),
+                    Rhs::Unsupported(column) => Err(column.unsupported($dtype)),
+                    _ => lhs.arith_in_place(&self.operand()?, op),
+                }
+            }
+            fn compare(&self, lhs: &Numeric<$t>, op: CompareOp) -> PyResult<Vec<bool>> {
+                match self {
+                    Rhs::Other(other) => other.compare(lhs, op),
+                    Rhs::Unsupported(column) => Err(column.unsupported($dtype)),
+                    _ => lhs.zip(&self.operand()?, |a, b| compare(op, a, b)),
+                }
+            }
+        }
+
+        /// The error for an integer which does not fit in the column's type.
+        fn out_of_range(value: &pyo3::types::PyLong) -> PyErr {
+            pyo3::exceptions::PyOverflowError::new_err(format!(
+                concat!("{} is out of range for ", $dtype),
+                value
+            ))
+        }
+
+        /// A column of another type, which is combined with this one after
+        /// converting both to a common type, like NumPy does.
+        #[derive(FromPyObject)]
+        enum Other<'a> {
+            $($other(PyRef<'a, $other>),)*
+        }
+
+        impl Other<'_> {
+            fn arith(
+                &self,
+                py: Python,
+                lhs: &Numeric<$t>,
+                op: Arith,
+            ) -> PyResult<PyObject> {
+                match self {
+                    $(
+                        Other::$other(column) => {
+                            let overflow = lhs.overflow()?;
+                            let result = lhs.try_zip_values(column.values()?, |a, b| {
+                                Element::arith(Cast::<$promoted>::cast(a), op, b.cast(), overflow)
+                            })?;
+                            Ok(PyArray1::from_vec(py, result).into_py(py))
+                        }
+                    )*
+                }
+            }
+            fn arith_in_place(&self, lhs: &Numeric<$t>, op: Arith) -> PyResult<()> {
+                match self {
+                    $(
+                        Other::$other(column) => {
+                            if <$promoted as Element>::KIND > <$t as Element>::KIND {
+                                return Err(PyTypeError::new_err(concat!(
+                                    "cannot cast the result of combining ",
+                                    stringify!($name),
+                                    " and ",
+                                    stringify!($other),
+                                    " back to ",
+                                    $dtype,
+                                )));
+                            }
+                            let overflow = lhs.overflow()?;
+                            lhs.try_update_values(column.values()?, |a, b| {
+                                Element::arith(Cast::<$promoted>::cast(a), op, b.cast(), overflow)?
+                                    .narrow(overflow)
+                            })
+                        }
+                    )*
+                }
+            }
+            fn compare(&self, lhs: &Numeric<$t>, op: CompareOp) -> PyResult<Vec<bool>> {
+                match self {
+                    $(
+                        Other::$other(column) => lhs.zip_values(column.values()?, |a, b| {
+                            compare::<$promoted>(op, a.cast(), b.cast())
+                        }),
+                    )*
+                }
+            }
+        }
+
+        #[doc = concat!("An array of ", $dtype, " values.")]
+        ///
+        /// Arithmetic and comparison operators also accept some columns of
+        /// other types. Both sides are converted to a common type first,
+        /// following NumPy's promotion rules. In-place operators raise
+        /// :class:`TypeError` if the result would have to be cast back to a
+        /// lesser kind, such as from float to int.
+        ///
+        /// Columns of any other type raise :class:`TypeError`, and
+        #[doc = concat!("integers which do not fit in ", $dtype, " raise :class:`OverflowError`.")]
+        #[pyclass(module = "xecs")]
+        pub struct $name(Numeric<$t>);
+
+        impl $name {
+            /// Copy the elements in the view. This is how other column types
+            /// read this one, but not every type is promoted with every other.
+            #[allow(dead_code)]
+            pub(crate) fn values(&self) -> PyResult<Vec<$t>> {
+                self.0.values()
+            }
+        }
+
+        #[pymethods]
+        impl $name {
+            #[staticmethod]
+            fn p_default_value() -> $t {
+                <$t>::default()
+            }
+            #[staticmethod]
+            fn p_from_value(value: $t, num: usize) -> PyResult<Self> {
+                Ok(Self(Numeric::from_vec(vec![value; num])))
+            }
+            #[staticmethod]
+            fn p_from_numpy(array: &PyArray1<$t>) -> PyResult<Self> {
+                Ok(Self(Numeric::from_vec(array.to_vec()?)))
+            }
+            #[staticmethod]
+            fn p_from_indices(
+                indices: &ArrayViewIndices,
+                default: $t,
+                overflow: Option<$crate::numeric::Overflow>,
+            ) -> PyResult<Self> {
+                let overflow = overflow.unwrap_or($crate::numeric::Overflow::Checked);
+                Ok(Self(Numeric::from_indices(indices, default, overflow)?))
+            }
+            fn p_new_view_with_indices(&self, indices: &ArrayViewIndices) -> Self {
+                Self(self.0.new_view_with_indices(indices))
+            }
+            fn p_column(&self) -> Column {
+                self.0.column()
+            }
+            /// Copy the elements into a NumPy array.
+            ///
+            /// Returns:
+            ///     numpy.ndarray: The NumPy array.
+            fn numpy(&self, py: Python) -> PyResult<Py<PyArray1<$t>>> {
+                Ok(PyArray1::from_vec(py, self.0.values()?).into_py(py))
+            }
+            /// Set the values of the array.
+            ///
+            /// Parameters:
+            #[doc = concat!("    values (", $py_type, " | list[", $py_type, "]): The new values.")]
+            /// Raises:
+            ///     ValueError: If the number of values does not match the
+            ///         length of the array.
+            fn fill(&mut self, values: Rhs) -> PyResult<()> {
+                self.0.update_with(&values.operand()?, |_, b| b)
+            }
+            /// Get the value at a specific index.
+            ///
+            /// Parameters:
+            ///     index (int): The index where the value is located.
+            ///         Negative indices count back from the end.
+            /// Returns:
+            #[doc = concat!("    ", $py_type, ": The value at `index`.")]
+            /// Raises:
+            ///     IndexError: If `index` is out of bounds.
+            fn get(&self, index: isize) -> PyResult<$t> {
+                self.0.get(index)
+            }
+            /// Get a string representation.
+            ///
+            /// Returns:
+            ///     str: The string representation.
+            fn to_str(&self) -> PyResult<String> {
+                Ok(format!(
+                    concat!("<xecs.", stringify!($name), " {:?}>"),
+                    self.0.values()?
+                ))
+            }
+            fn __str__(&self) -> PyResult<String> {
+                Ok(format!(
+                    concat!("<xecs.", stringify!($name), " {:#?}>"),
+                    self.0.values()?
+                ))
+            }
+            fn __repr__(&self) -> PyResult<String> {
+                self.__str__()
+            }
+            fn __len__(&self) -> PyResult<usize> {
+                self.0.len()
+            }
+            fn __getitem__(&self, key: GetItemKey) -> PyResult<Self> {
+                Ok(Self(self.0.getitem(&key)?))
+            }
+            fn __setitem__(&mut self, key: GetItemKey, rhs: Rhs) -> PyResult<()> {
+                self.0.setitem(&key, &rhs.operand()?)
+            }
+            fn __add__(&self, py: Python, rhs: Rhs) -> PyResult<PyObject> {
+                rhs.arith(py, &self.0, Arith::Add)
+            }
+            fn __iadd__(&mut self, rhs: Rhs) -> PyResult<()> {
+                rhs.arith_in_place(&self.0, Arith::Add)
+            }
+            fn __sub__(&self, py: Python, rhs: Rhs) -> PyResult<PyObject> {
+                rhs.arith(py, &self.0, Arith::Sub)
+            }
+            fn __isub__(&mut self, rhs: Rhs) -> PyResult<()> {
+                rhs.arith_in_place(&self.0, Arith::Sub)
+            }
+            fn __mul__(&self, py: Python, rhs: Rhs) -> PyResult<PyObject> {
+                rhs.arith(py, &self.0, Arith::Mul)
+            }
+            fn __imul__(&mut self, rhs: Rhs) -> PyResult<()> {
+                rhs.arith_in_place(&self.0, Arith::Mul)
+            }
+            fn __truediv__(&self, py: Python, rhs: Rhs) -> PyResult<PyObject> {
+                rhs.arith(py, &self.0, Arith::Div)
+            }
+            fn __itruediv__(&mut self, rhs: Rhs) -> PyResult<()> {
+                rhs.arith_in_place(&self.0, Arith::Div)
+            }
+            fn __floordiv__(&self, py: Python, rhs: Rhs) -> PyResult<PyObject> {
+                rhs.arith(py, &self.0, Arith::FloorDiv)
+            }
+            fn __ifloordiv__(&mut self, rhs: Rhs) -> PyResult<()> {
+                rhs.arith_in_place(&self.0, Arith::FloorDiv)
+            }
+            fn __mod__(&self, py: Python, rhs: Rhs) -> PyResult<PyObject> {
+                rhs.arith(py, &self.0, Arith::Rem)
+            }
+            fn __imod__(&mut self, rhs: Rhs) -> PyResult<()> {
+                rhs.arith_in_place(&self.0, Arith::Rem)
+            }
+            #[args(_modulo = "None")]
+            fn __pow__(
+                &self,
+                py: Python,
+                rhs: PowRhs,
+                _modulo: &PyAny,
+            ) -> PyResult<Py<PyArray1<$t>>> {
+                Ok(PyArray1::from_vec(py, rhs.pow(&self.0)?).into_py(py))
+            }
+            #[args(_modulo = "None")]
+            fn __ipow__(&mut self, rhs: PowRhs, _modulo: &PyAny) -> PyResult<()> {
+                rhs.ipow(&self.0)
+            }
+            fn __richcmp__(
+                &self,
+                py: Python,
+                other: Rhs,
+                op: CompareOp,
+            ) -> PyResult<Py<PyArray1<bool>>> {
+                let result = other.compare(&self.0, op)?;
+                Ok(PyArray1::from_vec(py, result).into_py(py))
+            }
+            /// Compute the elements limited to the range ``[min, max]``.
+            ///
+            /// Parameters:
+            #[doc = concat!("    min (", $py_type, "): The lower limit.")]
+            #[doc = concat!("    max (", $py_type, "): The upper limit.")]
+            /// Returns:
+            ///     numpy.ndarray: The clamped elements.
+            fn clamp(&self, py: Python, min: $t, max: $t) -> PyResult<Py<PyArray1<$t>>> {
+                let result = self.0.map(|a| $crate::numeric::clamp(a, min, max))?;
+                Ok(PyArray1::from_vec(py, result).into_py(py))
+            }
+            /// Limit the elements to the range ``[min, max]`` in-place.
+            ///
+            /// Parameters:
+            #[doc = concat!("    min (", $py_type, "): The lower limit.")]
+            #[doc = concat!("    max (", $py_type, "): The upper limit.")]
+            fn clamp_in_place(&mut self, min: $t, max: $t) -> PyResult<()> {
+                self.0.update(|a| $crate::numeric::clamp(a, min, max))
+            }
+            /// Add up the elements.
+            ///
+            /// Returns:
+            #[doc = concat!("    ", $py_type, ": The sum, which is 0 if the array is empty.")]
+            fn sum(&self) -> PyResult<<$t as $crate::numeric::Element>::Sum> {
+                self.0.sum()
+            }
+            /// Average the elements.
+            ///
+            /// Returns:
+            ///     float: The mean, which is NaN if the array is empty.
+            fn mean(&self) -> PyResult<f64> {
+                self.0.mean()
+            }
+            /// Find the smallest element.
+            ///
+            /// Returns:
+            #[doc = concat!("    ", $py_type, ": The smallest element.")]
+            /// Raises:
+            ///     ValueError: If the array is empty.
+            fn min(&self) -> PyResult<$t> {
+                Ok(self.0.min()?.1)
+            }
+            /// Find the largest element.
+            ///
+            /// Returns:
+            #[doc = concat!("    ", $py_type, ": The largest element.")]
+            /// Raises:
+            ///     ValueError: If the array is empty.
+            fn max(&self) -> PyResult<$t> {
+                Ok(self.0.max()?.1)
+            }
+            /// Find the index of the first smallest element.
+            ///
+            /// Returns:
+            ///     int: The index into this array.
+            /// Raises:
+            ///     ValueError: If the array is empty.
+            fn argmin(&self) -> PyResult<usize> {
+                Ok(self.0.min()?.0)
+            }
+            /// Find the index of the first largest element.
+            ///
+            /// Returns:
+            ///     int: The index into this array.
+            /// Raises:
+            ///     ValueError: If the array is empty.
+            fn argmax(&self) -> PyResult<usize> {
+                Ok(self.0.max()?.0)
+            }
+            /// Check if any element is non-zero.
+            ///
+            /// Returns:
+            ///     bool: :data:`False` if the array is empty.
+            fn any(&self) -> PyResult<bool> {
+                self.0.any()
+            }
+            /// Check if every element is non-zero.
+            ///
+            /// Returns:
+            ///     bool: :data:`True` if the array is empty.
+            fn all(&self) -> PyResult<bool> {
+                self.0.all()
+            }
+            $($methods)*
+        }
+    };
+}
+
+pub(crate) use numeric_column;
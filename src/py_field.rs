@@ -4,11 +4,16 @@ use pyo3::prelude::*;
 
 use crate::{
     array_view_indices::ArrayViewIndices,
+    change_ticks::ChangeTicks,
+    column::Column,
     error_handlers::{cannot_read, cannot_write},
+    getitem_key::GetItemKey,
 };
 
 #[pyclass]
 pub struct PyField {
+    change_ticks: ChangeTicks,
+    default: PyObject,
     array: Arc<RwLock<Vec<PyObject>>>,
     indices: ArrayViewIndices,
 }
@@ -17,24 +22,29 @@ pub struct PyField {
 impl PyField {
     #[staticmethod]
     fn p_from_indices(indices: &ArrayViewIndices, default: PyObject) -> PyResult<Self> {
+        let capacity = indices.0.read().map_err(cannot_read)?.capacity();
         Ok(Self {
-            array: Arc::new(RwLock::new(vec![
-                default;
-                indices
-                    .0
-                    .read()
-                    .map_err(cannot_read)?
-                    .capacity()
-            ])),
+            change_ticks: ChangeTicks::new(capacity),
+            array: Arc::new(RwLock::new(vec![default.clone(); capacity])),
+            default,
             indices: ArrayViewIndices(Arc::clone(&indices.0)),
         })
     }
     fn p_new_view_with_indices(&self, indices: &ArrayViewIndices) -> Self {
         Self {
+            change_ticks: self.change_ticks.share(),
+            default: self.default.clone(),
             array: Arc::clone(&self.array),
             indices: ArrayViewIndices(Arc::clone(&indices.0)),
         }
     }
+    fn p_column(&self) -> Column {
+        Column::new(
+            self.change_ticks.share(),
+            Arc::clone(&self.array),
+            self.default.clone(),
+        )
+    }
     fn fill(&mut self, py: Python, value: PyObject) -> PyResult<()> {
         let mut array = self.array.write().map_err(cannot_write)?;
         let indices = self.indices.0.read().map_err(cannot_read)?;
@@ -43,18 +53,26 @@ impl PyField {
                 *array.get_unchecked_mut(index as usize) = Py::clone_ref(&value, py);
             }
         }
-
+        self.change_ticks.mark(&indices)?;
         Ok(())
     }
-    fn get(&self, py: Python, index: usize) -> PyResult<PyObject> {
+    fn get(&self, py: Python, index: isize) -> PyResult<PyObject> {
+        let index = self.indices.get(index)?;
         let array = self.array.read().map_err(cannot_read)?;
-        let indices = self.indices.0.read().map_err(cannot_read)?;
-        Ok(Py::clone_ref(
-            unsafe { array.get_unchecked(*indices.get_unchecked(index) as usize) },
-            py,
-        ))
+        Ok(Py::clone_ref(&array[index as usize], py))
     }
     fn __len__(&self) -> PyResult<usize> {
         Ok(self.indices.0.read().map_err(cannot_read)?.len())
     }
+    fn __getitem__(&self, key: GetItemKey) -> PyResult<Self> {
+        Ok(Self {
+            change_ticks: self.change_ticks.share(),
+            default: self.default.clone(),
+            array: Arc::clone(&self.array),
+            indices: self.indices.select(&key)?,
+        })
+    }
+    fn __setitem__(&mut self, py: Python, key: GetItemKey, value: PyObject) -> PyResult<()> {
+        self.__getitem__(key)?.fill(py, value)
+    }
 }
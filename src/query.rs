@@ -1,57 +1,223 @@
 use crate::array_view_indices::MultipleArrayViewIndices;
 use crate::component_id::ComponentId;
 use crate::component_pool::ComponentPool;
+use crate::entity_id::EntityId;
+use crate::index::Index;
 use crate::map::Map;
+use crate::set::Set;
 use std::sync::{Arc, RwLock};
 
+/// The entities which hold a set of components and do not hold
+/// another set of excluded components.
+///
+/// The matched entities are cached and updated as entities gain or
+/// lose components, rather than being recalculated every time the
+/// query is run. They are kept sorted by entity id, so that results
+/// do not depend on hashing.
 pub struct Query {
-    first_component: ComponentId,
-    other_components: Vec<ComponentId>,
+    components: Vec<ComponentId>,
+    excluded_components: Vec<ComponentId>,
+    entity_ids: Vec<EntityId>,
+    // For each component, the index of each matched entity in the
+    // component pool. These are aligned with entity_ids.
+    indices: Vec<Vec<Index>>,
+    matched: Set<EntityId>,
 }
 
 impl Query {
-    pub fn new(first_component: ComponentId, other_components: Vec<ComponentId>) -> Self {
-        Self {
-            first_component,
-            other_components,
+    pub fn new(
+        components: Vec<ComponentId>,
+        excluded_components: Vec<ComponentId>,
+        pools: &Map<ComponentId, ComponentPool>,
+    ) -> Self {
+        let mut query = Self {
+            indices: vec![Vec::new(); components.len()],
+            components,
+            excluded_components,
+            entity_ids: Vec::new(),
+            matched: Set::new(),
+        };
+        let component_pools: Option<Vec<_>> = query
+            .components
+            .iter()
+            .map(|component_id| pools.get(component_id))
+            .collect();
+        if let Some(smallest_pool) = component_pools.and_then(|component_pools| {
+            component_pools
+                .into_iter()
+                .min_by_key(|pool| pool.entity_ids.len())
+        }) {
+            let entity_ids: Vec<_> = smallest_pool.entity_ids.iter().copied().collect();
+            query.add_entities(&entity_ids, pools);
         }
+        query
+    }
+    pub fn components(&self) -> &[ComponentId] {
+        &self.components
+    }
+    /// Get the number of matched entities.
+    pub fn len(&self) -> usize {
+        self.entity_ids.len()
     }
-    pub fn result(&self, pools: &Map<ComponentId, ComponentPool>) -> MultipleArrayViewIndices {
-        let first_component = pools.get(&self.first_component).unwrap();
-        let other_components: Vec<_> = self
-            .other_components
+    /// Get the index of each matched entity in the pool of one of the
+    /// components of the query.
+    pub fn indices_of(&self, component_id: ComponentId) -> &[Index] {
+        let position = self
+            .components
             .iter()
-            .map(|component_id| pools.get(component_id).unwrap())
+            .position(|&query_component| query_component == component_id)
+            .unwrap();
+        self.component_indices(position)
+    }
+    fn component_indices(&self, position: usize) -> &[Index] {
+        unsafe { self.indices.get_unchecked(position) }
+    }
+    /// Add the entities which hold every component of the query, hold
+    /// none of its excluded components and are not matched already.
+    pub fn add_entities(
+        &mut self,
+        entity_ids: &[EntityId],
+        pools: &Map<ComponentId, ComponentPool>,
+    ) {
+        let component_pools: Option<Vec<_>> = self
+            .components
+            .iter()
+            .map(|component_id| pools.get(component_id))
             .collect();
-
-        // TODO: You can probabably cache the intersection. If a component
-        // is added or removed the cache can be cleared.
-        // There is probably also an intelligent way of updating the intersection
-        // if a component is added or removed -- rather than recalculating it
-        // from scratch. This is because you know which queries are affected when
-        // a component is added or removed from an entity the query intersection
-        // only needs to be updated with those entities.
-        let intersection = other_components
+        let Some(component_pools) = component_pools else {
+            return;
+        };
+        let excluded_pools: Vec<_> = self
+            .excluded_components
             .iter()
-            .map(|pool| &pool.entity_ids)
-            .fold(first_component.entity_ids.clone(), |acc, entity_ids| {
-                acc.intersection(entity_ids).cloned().collect()
-            });
-        let mut result = Vec::with_capacity(other_components.len() + 1);
-        result.push(Arc::new(RwLock::new(
-            intersection
+            .filter_map(|component_id| pools.get(component_id))
+            .collect();
+        let num_matched = self.entity_ids.len();
+        for &entity_id in entity_ids {
+            if self.matched.contains(&entity_id)
+                || excluded_pools
+                    .iter()
+                    .any(|pool| pool.entity_ids.contains(&entity_id))
+            {
+                continue;
+            }
+            let entity_indices: Option<Vec<_>> = component_pools
                 .iter()
-                .map(|entity_id| *first_component.entity_indices.get(entity_id).unwrap())
+                .map(|pool| pool.entity_indices.get(&entity_id))
+                .collect();
+            if let Some(entity_indices) = entity_indices {
+                self.matched.insert(entity_id);
+                self.entity_ids.push(entity_id);
+                for (indices, &index) in self.indices.iter_mut().zip(entity_indices) {
+                    indices.push(index);
+                }
+            }
+        }
+        // Newly spawned entities usually have larger ids than existing
+        // ones, so sorting is only needed when ids are reused.
+        if !self.entity_ids[num_matched.saturating_sub(1)..]
+            .windows(2)
+            .all(|pair| pair[0] < pair[1])
+        {
+            self.sort();
+        }
+    }
+    /// Remove entities which are matched by the query.
+    pub fn remove_entities(&mut self, entity_ids: &[EntityId]) {
+        let mut num_removed = 0;
+        for entity_id in entity_ids {
+            if self.matched.remove(entity_id) {
+                num_removed += 1;
+            }
+        }
+        if num_removed == 0 {
+            return;
+        }
+        let keep: Vec<_> = self
+            .entity_ids
+            .iter()
+            .map(|entity_id| self.matched.contains(entity_id))
+            .collect();
+        retain_by_mask(&mut self.entity_ids, &keep);
+        for indices in self.indices.iter_mut() {
+            retain_by_mask(indices, &keep);
+        }
+    }
+    fn sort(&mut self) {
+        let mut order: Vec<_> = (0..self.entity_ids.len()).collect();
+        order.sort_unstable_by_key(|&position| unsafe { *self.entity_ids.get_unchecked(position) });
+        self.entity_ids = reorder(&self.entity_ids, &order);
+        for indices in self.indices.iter_mut() {
+            *indices = reorder(indices, &order);
+        }
+    }
+    /// Get the indices of the matched entities.
+    ///
+    /// `components` holds the position of each requested component
+    /// in the components of the query. If `keep` is given, only the
+    /// matched entities where it is true are included.
+    pub fn result(&self, components: &[usize], keep: Option<&[bool]>) -> MultipleArrayViewIndices {
+        MultipleArrayViewIndices::new(
+            components
+                .iter()
+                .map(|&component| {
+                    let indices = self.component_indices(component);
+                    Arc::new(RwLock::new(match keep {
+                        Some(keep) => select_by_mask(indices, keep),
+                        None => indices.to_vec(),
+                    }))
+                })
                 .collect(),
-        )));
-        result.extend(other_components.iter().map(|pool| {
-            Arc::new(RwLock::new(
-                intersection
-                    .iter()
-                    .map(|entity_id| *pool.entity_indices.get(entity_id).unwrap())
-                    .collect(),
-            ))
-        }));
-        MultipleArrayViewIndices::new(result)
+        )
     }
+    /// Get whether each matched entity holds an optional component,
+    /// and the indices of the entities which do. If `keep` is given,
+    /// only the matched entities where it is true are included.
+    pub fn optional_result(
+        &self,
+        component_id: ComponentId,
+        pools: &Map<ComponentId, ComponentPool>,
+        keep: Option<&[bool]>,
+    ) -> (Vec<bool>, Vec<Index>) {
+        let entity_ids = match keep {
+            Some(keep) => select_by_mask(&self.entity_ids, keep),
+            None => self.entity_ids.clone(),
+        };
+        let mut mask = Vec::with_capacity(entity_ids.len());
+        let mut indices = Vec::new();
+        if let Some(pool) = pools.get(&component_id) {
+            for entity_id in entity_ids.iter() {
+                match pool.entity_indices.get(entity_id) {
+                    Some(&index) => {
+                        mask.push(true);
+                        indices.push(index);
+                    }
+                    None => mask.push(false),
+                }
+            }
+        } else {
+            mask.resize(entity_ids.len(), false);
+        }
+        (mask, indices)
+    }
+}
+
+fn retain_by_mask<T>(vec: &mut Vec<T>, keep: &[bool]) {
+    let mut keep = keep.iter();
+    vec.retain(|_| *keep.next().unwrap());
+}
+
+fn select_by_mask<T: Copy>(vec: &[T], keep: &[bool]) -> Vec<T> {
+    vec.iter()
+        .zip(keep)
+        .filter(|(_, &keep)| keep)
+        .map(|(&value, _)| value)
+        .collect()
+}
+
+fn reorder<T: Copy>(vec: &[T], order: &[usize]) -> Vec<T> {
+    order
+        .iter()
+        .map(|&position| unsafe { *vec.get_unchecked(position) })
+        .collect()
 }
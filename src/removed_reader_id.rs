@@ -0,0 +1 @@
+pub type RemovedReaderId = usize;
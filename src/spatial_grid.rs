@@ -0,0 +1,145 @@
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use crate::array_view_indices::ArrayViewIndices;
+use crate::combinations::{gather_pairs, num_entities, read_all, Pairs};
+use crate::error_handlers::check_length;
+use crate::float32::Float32;
+use crate::map::Map;
+
+type Cell = (i64, i64);
+
+/// A uniform grid of square cells holding 2D points.
+///
+/// Each point is only compared with the points in nearby cells, so
+/// finding the points which are close together does not need to go
+/// through every pair. For a :class:`Vec2`, pass its ``x`` and ``y``.
+///
+/// Parameters:
+///     x (Float32): The x coordinates of the points.
+///     y (Float32): The y coordinates of the points.
+///     cell_size (float): The side length of each cell. Searches are
+///         fastest when this is close to the search radius.
+/// Raises:
+///     ValueError: If `x` and `y` have different lengths, or
+///         `cell_size` is not positive and finite.
+#[pyclass(module = "xecs")]
+pub struct SpatialGrid {
+    cell_size: f32,
+    points: Vec<(f32, f32)>,
+    cells: Map<Cell, Vec<usize>>,
+}
+
+#[pymethods]
+impl SpatialGrid {
+    #[new]
+    fn new(x: PyRef<Float32>, y: PyRef<Float32>, cell_size: f32) -> PyResult<Self> {
+        if !(cell_size.is_finite() && cell_size > 0.0) {
+            return Err(PyValueError::new_err(
+                "cell_size must be positive and finite",
+            ));
+        }
+        let points = read_points(&x, &y)?;
+        let mut cells: Map<Cell, Vec<usize>> = Map::new();
+        for (position, &point) in points.iter().enumerate() {
+            cells
+                .entry(cell(point, cell_size))
+                .or_default()
+                .push(position);
+        }
+        Ok(Self {
+            cell_size,
+            points,
+            cells,
+        })
+    }
+    fn __len__(&self) -> usize {
+        self.points.len()
+    }
+    /// Find every pair of different points at most `radius` apart.
+    ///
+    /// Like :meth:`Query.product_2`, each pair appears in both orders.
+    ///
+    /// Parameters:
+    ///     radius (float): The largest distance between paired points.
+    ///     indices (list[ArrayViewIndices]): The indices of each
+    ///         component of the query which the points belong to, in
+    ///         the same order as the points.
+    /// Returns:
+    ///     tuple[list[ArrayViewIndices], list[ArrayViewIndices]]: The
+    ///     indices of each component for the first and the second point
+    ///     of every pair.
+    /// Raises:
+    ///     ValueError: If `radius` is negative or not finite, or
+    ///         `indices` do not have one index for each point.
+    fn pairs_within(&self, radius: f32, indices: Vec<PyRef<ArrayViewIndices>>) -> PyResult<Pairs> {
+        if !(radius.is_finite() && radius >= 0.0) {
+            return Err(PyValueError::new_err(
+                "radius must be non-negative and finite",
+            ));
+        }
+        let indices = read_all(&indices)?;
+        if !indices.is_empty() {
+            check_length(self.points.len(), num_entities(&indices))?;
+        }
+        let pairs = self.find_pairs(radius);
+        Ok(gather_pairs(
+            &indices,
+            &indices,
+            pairs.len(),
+            pairs.into_iter(),
+        ))
+    }
+}
+
+impl SpatialGrid {
+    /// Find the positions of every pair of different points at most
+    /// `radius` apart.
+    fn find_pairs(&self, radius: f32) -> Vec<(usize, usize)> {
+        let reach = (radius / self.cell_size).ceil();
+        let cells_to_search = (2.0 * reach as f64 + 1.0).powi(2);
+        let radius_squared = radius * radius;
+        let mut pairs = Vec::new();
+        for (i, &point) in self.points.iter().enumerate() {
+            let mut check = |j: usize| {
+                let (x1, y1) = point;
+                let (x2, y2) = self.points[j];
+                let distance_squared = (x2 - x1).powi(2) + (y2 - y1).powi(2);
+                if i != j && distance_squared <= radius_squared {
+                    pairs.push((i, j));
+                }
+            };
+            if cells_to_search > self.cells.len() as f64 {
+                // Most cells in reach would be empty, so it is quicker to
+                // check every point.
+                (0..self.points.len()).for_each(check);
+                continue;
+            }
+            let reach = reach as i64;
+            let (x, y) = cell(point, self.cell_size);
+            for cell_x in x.saturating_sub(reach)..=x.saturating_add(reach) {
+                for cell_y in y.saturating_sub(reach)..=y.saturating_add(reach) {
+                    if let Some(cell) = self.cells.get(&(cell_x, cell_y)) {
+                        cell.iter().for_each(|&j| check(j));
+                    }
+                }
+            }
+        }
+        pairs
+    }
+}
+
+/// Read the points whose coordinates are in `x` and `y`.
+pub fn read_points(x: &Float32, y: &Float32) -> PyResult<Vec<(f32, f32)>> {
+    let x = x.values()?;
+    let y = y.values()?;
+    check_length(x.len(), y.len())?;
+    Ok(x.into_iter().zip(y).collect())
+}
+
+fn cell((x, y): (f32, f32), cell_size: f32) -> Cell {
+    (
+        (x / cell_size).floor() as i64,
+        (y / cell_size).floor() as i64,
+    )
+}
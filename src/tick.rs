@@ -0,0 +1,30 @@
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Arc;
+
+/// A point in time used for change detection.
+pub type Tick = u32;
+
+/// The tick at which writes are currently recorded.
+///
+/// Each app has its own, which it shares with the columns of its pools.
+/// Columns which are not part of an app have one of their own.
+#[derive(Clone)]
+pub struct CurrentTick(Arc<AtomicU32>);
+
+impl Default for CurrentTick {
+    fn default() -> Self {
+        // Ticks start after 0, so that every write is newer than a query
+        // which has never run.
+        Self(Arc::new(AtomicU32::new(1)))
+    }
+}
+
+impl CurrentTick {
+    pub fn get(&self) -> Tick {
+        self.0.load(Ordering::Relaxed)
+    }
+    /// Move to the next tick and return it.
+    pub fn advance(&self) -> Tick {
+        self.0.fetch_add(1, Ordering::Relaxed) + 1
+    }
+}
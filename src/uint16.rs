@@ -0,0 +1,6 @@
+use crate::bool::Bool;
+use crate::numeric::numeric_column;
+
+numeric_column!(UInt16, u16, "uint16", "int", uint, [
+    Bool => u16,
+]);
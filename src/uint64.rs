@@ -0,0 +1,6 @@
+use crate::bool::Bool;
+use crate::numeric::numeric_column;
+
+numeric_column!(UInt64, u64, "uint64", "int", uint, [
+    Bool => u64,
+]);
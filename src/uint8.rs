@@ -0,0 +1,6 @@
+use crate::bool::Bool;
+use crate::numeric::numeric_column;
+
+numeric_column!(UInt8, u8, "uint8", "int", uint, [
+    Bool => u8,
+]);
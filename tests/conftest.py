@@ -0,0 +1,35 @@
+import typing
+
+import numpy as np
+import pytest
+import xecs as xx
+
+COLUMNS = (
+    (xx.Float32, np.float32),
+    (xx.Float64, np.float64),
+    (xx.Int8, np.int8),
+    (xx.Int16, np.int16),
+    (xx.Int32, np.int32),
+    (xx.Int64, np.int64),
+    (xx.UInt8, np.uint8),
+    (xx.UInt16, np.uint16),
+    (xx.UInt32, np.uint32),
+    (xx.UInt64, np.uint64),
+)
+
+
+@pytest.fixture(params=COLUMNS, ids=lambda column: column[0].__name__)
+def column_and_dtype(
+    request: pytest.FixtureRequest,
+) -> tuple[typing.Any, typing.Any]:
+    return request.param
+
+
+@pytest.fixture
+def column(column_and_dtype: tuple[typing.Any, typing.Any]) -> typing.Any:
+    return column_and_dtype[0]
+
+
+@pytest.fixture
+def dtype(column_and_dtype: tuple[typing.Any, typing.Any]) -> typing.Any:
+    return column_and_dtype[1]
@@ -0,0 +1,133 @@
+import operator
+import typing
+
+import numpy as np
+import pytest
+import xecs as xx
+
+
+@pytest.mark.parametrize(
+    "op",
+    (
+        operator.and_,
+        operator.or_,
+        operator.xor,
+        operator.lshift,
+        operator.rshift,
+    ),
+)
+@pytest.mark.parametrize(
+    ("column", "dtype"),
+    (
+        (xx.Int32, np.int32),
+        (xx.UInt32, np.uint32),
+    ),
+)
+def test_integer_operator_matches_numpy(
+    column: typing.Any,
+    dtype: typing.Any,
+    op: typing.Any,
+) -> None:
+    lhs = np.array([0b1100, 0b1010, 7, 1, 2**20], dtype=dtype)
+    rhs = np.array([0b1010, 0b0110, 1, 31, 40], dtype=dtype)
+    xs = column.p_from_numpy(lhs)
+    expected = op(lhs, rhs)
+    assert np.array_equal(op(xs, column.p_from_numpy(rhs)), expected)
+    assert np.array_equal(op(xs, rhs), expected)
+    assert np.array_equal(op(xs, rhs.tolist()), expected)
+    assert np.array_equal(op(xs, 3), op(lhs, dtype(3)))
+
+
+@pytest.mark.parametrize(
+    "iop",
+    (
+        operator.iand,
+        operator.ior,
+        operator.ixor,
+        operator.ilshift,
+        operator.irshift,
+    ),
+)
+@pytest.mark.parametrize(
+    ("column", "dtype"),
+    (
+        (xx.Int32, np.int32),
+        (xx.UInt32, np.uint32),
+    ),
+)
+def test_integer_in_place_operator_matches_numpy(
+    column: typing.Any,
+    dtype: typing.Any,
+    iop: typing.Any,
+) -> None:
+    lhs = np.array([0b1100, 0b1010, 7], dtype=dtype)
+    rhs = np.array([0b1010, 0b0110, 2], dtype=dtype)
+    xs = column.p_from_numpy(lhs)
+    iop(xs, rhs)
+    assert np.array_equal(xs.numpy(), iop(lhs.copy(), rhs))
+
+
+def test_shifting_negative_values() -> None:
+    values = np.array([-8, -8, -8, 8], dtype=np.int32)
+    shifts = np.array([1, 40, -1, 40], dtype=np.int32)
+    xs = xx.Int32.p_from_numpy(values)
+    assert np.array_equal(xs >> shifts, values >> shifts)
+    assert np.array_equal(xs << shifts, values << shifts)
+
+
+@pytest.mark.parametrize(
+    ("column", "dtype"),
+    (
+        (xx.Int32, np.int32),
+        (xx.UInt32, np.uint32),
+    ),
+)
+def test_integer_invert(column: typing.Any, dtype: typing.Any) -> None:
+    values = np.array([0, 1, 5], dtype=dtype)
+    xs = column.p_from_numpy(values)
+    assert np.array_equal(~xs, ~values)
+    xs.invert_in_place()
+    assert np.array_equal(xs.numpy(), ~values)
+
+
+@pytest.mark.parametrize(
+    "op",
+    (operator.and_, operator.or_, operator.xor),
+)
+def test_bool_operator_matches_numpy(op: typing.Any) -> None:
+    lhs = np.array([True, True, False, False])
+    rhs = np.array([True, False, True, False])
+    xs = xx.Bool.p_from_numpy(lhs)
+    expected = op(lhs, rhs)
+    assert np.array_equal(op(xs, xx.Bool.p_from_numpy(rhs)), expected)
+    assert np.array_equal(op(xs, rhs), expected)
+    assert np.array_equal(op(xs, rhs.tolist()), expected)
+    assert np.array_equal(op(xs, True), op(lhs, True))
+
+
+@pytest.mark.parametrize(
+    "iop",
+    (operator.iand, operator.ior, operator.ixor),
+)
+def test_bool_in_place_operator_matches_numpy(iop: typing.Any) -> None:
+    lhs = np.array([True, True, False, False])
+    rhs = np.array([True, False, True, False])
+    xs = xx.Bool.p_from_numpy(lhs)
+    iop(xs, rhs)
+    assert np.array_equal(xs.numpy(), iop(lhs.copy(), rhs))
+
+
+def test_bool_in_place_operator_with_view_of_same_array() -> None:
+    xs = xx.Bool.p_from_numpy(np.array([True, False, True, True]))
+    first = np.array([True, True, False, False])
+    operator.iand(xs[first], xs[~first])
+    assert np.array_equal(xs.numpy(), [True, False, True, True])
+    operator.ixor(xs[first], xs[~first])
+    assert np.array_equal(xs.numpy(), [False, True, True, True])
+
+
+def test_bool_invert_only_changes_view() -> None:
+    xs = xx.Bool.p_from_numpy(np.array([True, False, True]))
+    assert np.array_equal(~xs, [False, True, False])
+    xs[np.array([False, True, True])].invert_in_place()
+    assert np.array_equal(xs.numpy(), [True, True, False])
@@ -0,0 +1,41 @@
+import numpy as np
+import pytest
+import xecs as xx
+
+
+def test_get() -> None:
+    xs = xx.Bool.p_from_numpy(np.array([True, False, False, True]))
+    view = xs[np.array([False, True, True, True])]
+    assert not view.get(0)
+    assert view.get(-1)
+    with pytest.raises(IndexError, match="length 3"):
+        view.get(3)
+    with pytest.raises(IndexError, match="length 3"):
+        view.get(-4)
+
+
+def test_length_mismatch() -> None:
+    xs = xx.Bool.p_from_numpy(np.array([True, False, True]))
+    short = np.array([False, False])
+    with pytest.raises(ValueError, match="3 values .* got 2"):
+        xs.fill(short)
+    with pytest.raises(ValueError, match="3 values .* got 4"):
+        xs.fill([False] * 4)
+    with pytest.raises(ValueError, match="3 values .* got 2"):
+        xs.fill(xx.Bool.p_from_numpy(short))
+    with pytest.raises(ValueError, match="3 values .* got 2"):
+        xs[np.ones(3, dtype=np.bool_)] = short
+    with pytest.raises(ValueError, match="3 values .* got 2"):
+        xs[np.ones(2, dtype=np.bool_)] = False
+    with pytest.raises(ValueError, match="3 values .* got 2"):
+        xs == short
+    with pytest.raises(ValueError, match="3 values .* got 2"):
+        xs &= short
+    assert np.array_equal(xs.numpy(), [True, False, True])
+
+
+def test_fill_from_view_of_same_array() -> None:
+    xs = xx.Bool.p_from_numpy(np.array([False, False, True, True]))
+    first = np.array([True, True, False, False])
+    xs[first].fill(xs[~first])
+    assert np.array_equal(xs.numpy(), [True, True, True, True])
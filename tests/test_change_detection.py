@@ -0,0 +1,143 @@
+import pytest
+import xecs as xx
+
+
+class Position(xx.Component):
+    x: xx.Float32
+
+
+class Health(xx.Component):
+    value: xx.Float32
+
+
+class Counts(xx.Resource):
+    values: list[int]
+
+
+def test_added_entities_are_seen_once(app: xx.SimulationApp) -> None:
+    app.add_system(count_added)
+    app.run(3, xx.Duration.from_millis(1))
+    assert app.world.get_resource(Counts).values == [4, 0, 0]
+
+
+def test_spawned_entities_are_added(app: xx.SimulationApp) -> None:
+    app.add_system(spawn_one)
+    app.add_system(count_added)
+    app.run(3, xx.Duration.from_millis(1))
+    assert app.world.get_resource(Counts).values == [5, 1, 1]
+
+
+def test_added_entities_are_changed(app: xx.SimulationApp) -> None:
+    app.add_system(count_changed)
+    app.run(2, xx.Duration.from_millis(1))
+    assert app.world.get_resource(Counts).values == [4, 0]
+
+
+def test_writes_are_changes(app: xx.SimulationApp) -> None:
+    app.add_system(count_changed)
+    app.add_system(move_first)
+    app.run(3, xx.Duration.from_millis(1))
+    assert app.world.get_resource(Counts).values == [4, 1, 1]
+
+
+def test_in_place_operators_are_changes(app: xx.SimulationApp) -> None:
+    app.add_system(count_changed)
+    app.add_system(move_all)
+    app.run(3, xx.Duration.from_millis(1))
+    assert app.world.get_resource(Counts).values == [4, 4, 4]
+
+
+def test_system_does_not_see_its_own_writes(app: xx.SimulationApp) -> None:
+    app.add_system(count_changed_and_move)
+    app.run(3, xx.Duration.from_millis(1))
+    assert app.world.get_resource(Counts).values == [4, 0, 0]
+
+
+def test_changed_filter_on_other_component(app: xx.SimulationApp) -> None:
+    app.add_system(count_health_changed)
+    app.add_system(heal_first)
+    app.run(3, xx.Duration.from_millis(1))
+    assert app.world.get_resource(Counts).values == [2, 1, 1]
+
+
+def test_apps_keep_their_own_ticks() -> None:
+    first, second = new_app(), new_app()
+    first.add_system(count_changed)
+    first.add_system(move_first)
+    second.add_system(count_changed_and_move)
+    for _ in range(3):
+        first.update(xx.Duration.from_millis(1))
+        second.update(xx.Duration.from_millis(1))
+    assert first.world.get_resource(Counts).values == [4, 1, 1]
+    assert second.world.get_resource(Counts).values == [4, 0, 0]
+
+
+def count_added(
+    counts: Counts,
+    query: xx.Query[xx.EntityId, xx.Added[Position]],
+) -> None:
+    counts.values.append(len(query.result()))
+
+
+def count_changed(
+    counts: Counts,
+    query: xx.Query[Position, xx.Changed[Position]],
+) -> None:
+    counts.values.append(len(query.result()))
+
+
+def count_changed_and_move(
+    counts: Counts,
+    query: xx.Query[Position, xx.Changed[Position]],
+) -> None:
+    position = query.result()
+    counts.values.append(len(position))
+    position.x += 1
+
+
+def count_health_changed(
+    counts: Counts,
+    query: xx.Query[Position, xx.Changed[Health]],
+) -> None:
+    counts.values.append(len(query.result()))
+
+
+def spawn_one(commands: xx.Commands) -> None:
+    commands.spawn((Position,), 1)
+
+
+def move_first(query: xx.Query[Position]) -> None:
+    position = query.result()
+    position.x[position.x.numpy() == 0] = 0
+
+
+def move_all(query: xx.Query[Position]) -> None:
+    position = query.result()
+    position.x += 1
+
+
+def heal_first(query: xx.Query[Health]) -> None:
+    health = query.result()
+    health[health.value.numpy() == 0].value.fill(0)
+
+
+def spawn_entities(world: xx.World, commands: xx.Commands) -> None:
+    (positioni,) = commands.spawn((Position,), 2)
+    world.get_view(Position, positioni).x.fill([0, 1])
+    (positioni, healthi) = commands.spawn((Position, Health), 2)
+    world.get_view(Position, positioni).x.fill([2, 3])
+    world.get_view(Health, healthi).value.fill([0, 1])
+
+
+@pytest.fixture
+def app() -> xx.SimulationApp:
+    return new_app()
+
+
+def new_app() -> xx.SimulationApp:
+    app = xx.SimulationApp(num_entities=10)
+    app.add_pool(Position.create_pool(10))
+    app.add_pool(Health.create_pool(10))
+    app.add_resource(Counts([]))
+    app.add_startup_system(spawn_entities)
+    return app
@@ -1,3 +1,5 @@
+import typing
+
 import numpy as np
 import pytest
 import xecs as xx
@@ -53,6 +55,130 @@ def get_product_two_components(
     )
 
 
+def test_combinations_2_one_component(app: xx.RealTimeApp) -> None:
+    app.add_system(get_combinations_one_component)
+    app.update()
+
+
+def test_combinations_2_two_components(app: xx.RealTimeApp) -> None:
+    app.add_system(get_combinations_two_components)
+    app.update()
+
+
+def get_combinations_one_component(query: xx.Query[tuple[One]]) -> None:
+    (one1,), (one2,) = query.combinations_2()
+    result = one1.x + one2.x
+    result.sort()
+    assert np.all(np.equal(result, [3, 4, 5, 5, 6, 7]))
+
+
+def get_combinations_two_components(
+    query: xx.Query[tuple[Two, Three]],
+) -> None:
+    (two1, three1), (two2, three2) = query.combinations_2()
+    pairs = sorted(
+        tuple(sorted(pair)) for pair in zip(two1.y.numpy(), two2.y.numpy())
+    )
+    assert pairs == [(1, 2), (1, 3), (1, 4), (2, 3), (2, 4), (3, 4)]
+    assert np.array_equal(three1.z.numpy(), two1.y.numpy() * 10)
+    assert np.array_equal(three2.z.numpy(), two2.y.numpy() * 10)
+
+
+def test_product_with_tuple_queries(app: xx.RealTimeApp) -> None:
+    app.add_system(get_product_with_tuple_queries)
+    app.update()
+
+
+def test_product_with_single_queries(app: xx.RealTimeApp) -> None:
+    app.add_system(get_product_with_single_queries)
+    app.update()
+
+
+def get_product_with_tuple_queries(
+    ones: xx.Query[tuple[One]],
+    others: xx.Query[tuple[Two, Three]],
+) -> None:
+    (one,), (two, three) = ones.product_with(others)
+    assert len(one) == len(two) == 16
+    result = one.x + two.y
+    result.sort()
+    expected = np.add.outer([1, 2, 3, 4], [1, 2, 3, 4]).ravel()
+    expected.sort()
+    assert np.array_equal(result, expected)
+    assert np.array_equal(three.z.numpy(), two.y.numpy() * 10)
+
+
+def get_product_with_single_queries(
+    ones: xx.Query[One],
+    threes: xx.Query[Three],
+) -> None:
+    three, one = threes.product_with(ones)
+    pairs = sorted(zip(three.z.numpy(), one.x.numpy()))
+    assert pairs == [(z, x) for z in (10, 20, 30, 40) for x in (1, 2, 3, 4)]
+
+
+def test_chunks_match_eager_pairs(app: xx.RealTimeApp) -> None:
+    app.add_system(check_chunks_match_eager_pairs)
+    app.update()
+
+
+def check_chunks_match_eager_pairs(
+    ones: xx.Query[tuple[One]],
+    others: xx.Query[tuple[Two, Three]],
+) -> None:
+    for chunk_size in (1, 5, 12, 100):
+        assert_chunks_match(
+            ones.product_2(),
+            ones.product_2_chunks(chunk_size),
+            chunk_size,
+        )
+        assert_chunks_match(
+            others.combinations_2(),
+            others.combinations_2_chunks(chunk_size),
+            chunk_size,
+        )
+        assert_chunks_match(
+            ones.product_with(others),
+            ones.product_with_chunks(others, chunk_size),
+            chunk_size,
+        )
+
+
+def test_chunk_size_must_be_positive(app: xx.RealTimeApp) -> None:
+    app.add_system(check_chunk_size_must_be_positive)
+    app.update()
+
+
+def check_chunk_size_must_be_positive(query: xx.Query[One]) -> None:
+    with pytest.raises(ValueError, match="chunk_size"):
+        query.product_2_chunks(0)
+
+
+def assert_chunks_match(
+    pairs: tuple[typing.Any, typing.Any],
+    chunks: typing.Iterator[tuple[typing.Any, typing.Any]],
+    chunk_size: int,
+) -> None:
+    chunk_list = list(chunks)
+    assert all(0 < len(first[0]) <= chunk_size for first, _ in chunk_list)
+    for eager, chunk_halves in zip(pairs, zip(*chunk_list), strict=True):
+        for component, component_chunks in zip(
+            eager, zip(*chunk_halves), strict=True
+        ):
+            assert np.array_equal(
+                np.concatenate([values(chunk) for chunk in component_chunks]),
+                values(component),
+            )
+
+
+def values(component: One | Two | Three) -> typing.Any:
+    if isinstance(component, One):
+        return component.x.numpy()
+    if isinstance(component, Two):
+        return component.y.numpy()
+    return component.z.numpy()
+
+
 def spawn_entities(world: xx.World, commands: xx.Commands) -> None:
     (onei,) = commands.spawn((One,), 4)
     world.get_view(One, onei).x.fill([1, 2, 3, 4])
@@ -1,6 +1,7 @@
 from typing import TypeVar
 
 import numpy as np
+import pytest
 import xecs as xx
 
 T = TypeVar("T", bound=xx.Component | xx.Struct)
@@ -31,6 +32,15 @@ class MyComponent(xx.Component):
     g: xx.PyField[str] = xx.py_field(default="world")
 
 
+def test_spawning_into_pool_of_app_raises() -> None:
+    pool = MyComponent.create_pool(10)
+    app = xx.RealTimeApp(num_entities=10)
+    app.add_pool(pool)
+    with pytest.raises(RuntimeError, match="Commands.spawn"):
+        pool.p_spawn(1)
+    assert len(pool.p_component) == 0
+
+
 def test_spawning_entities_updates_views_of_children() -> None:
     pool = MyComponent.create_pool(100)
     pool.p_spawn(50)
@@ -106,6 +116,29 @@ def test_py_field_default_value_is_used() -> None:
     assert component.g.get(6) == "world"
 
 
+def test_py_field_get_is_bounds_checked() -> None:
+    app = xx.RealTimeApp(num_entities=10)
+    app.add_pool(MyComponent.create_pool(10))
+    app.add_startup_system(spawn_three_components)
+    app.add_system(check_py_field_get_is_bounds_checked)
+    app.update()
+
+
+def spawn_three_components(commands: xx.Commands) -> None:
+    commands.spawn((MyComponent,), 3)
+
+
+def check_py_field_get_is_bounds_checked(
+    query: xx.Query[MyComponent],
+) -> None:
+    component = query.result()
+    assert component.g.get(-1) == "world"
+    with pytest.raises(IndexError):
+        component.g.get(3)
+    with pytest.raises(IndexError):
+        component.g.get(-4)
+
+
 class StructWithDefaults(xx.Struct):
     a: xx.Float = xx.float_(default=1.0)
     b: xx.Float32 = xx.float32(default=2.0)
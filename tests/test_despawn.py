@@ -0,0 +1,126 @@
+import numpy as np
+import pytest
+import xecs as xx
+
+
+class One(xx.Component):
+    x: xx.Float32
+
+
+class Two(xx.Component):
+    y: xx.Float32
+
+
+def test_despawned_entities_are_not_queried(app: xx.SimulationApp) -> None:
+    app.add_system(despawn_odd_entities)
+    app.update(xx.Duration.from_millis(1))
+    app.add_system(check_odd_entities_despawned)
+    app.update(xx.Duration.from_millis(1))
+
+
+def test_despawned_slots_are_reused(app: xx.SimulationApp) -> None:
+    app.add_system(respawn_entities)
+    for _ in range(10):
+        app.update(xx.Duration.from_millis(1))
+
+
+def test_despawned_entity_ids_are_not_alive(app: xx.SimulationApp) -> None:
+    app.add_system(check_stale_entity_ids)
+    app.update(xx.Duration.from_millis(1))
+
+
+def test_entity_ids_are_unique_after_many_reuses() -> None:
+    app = xx.SimulationApp(num_entities=2)
+    app.add_system(respawn_one_entity_many_times)
+    app.update(xx.Duration.from_millis(1))
+
+
+def despawn_odd_entities(
+    commands: xx.Commands,
+    query: xx.Query[xx.EntityId],
+) -> None:
+    entity_id = query.result()
+    entity_ids = entity_id.value.numpy()
+    commands.despawn(entity_ids[entity_ids % 2 == 1])
+
+
+def check_odd_entities_despawned(
+    world: xx.World,
+    query_one: xx.Query[tuple[xx.EntityId, One]],
+    query_two: xx.Query[tuple[xx.EntityId, Two]],
+) -> None:
+    entity_id, one = query_one.result()
+    assert set(entity_id.value.numpy()) == {0, 2, 4, 6, 8}
+    assert set(one.x.numpy()) == {0, 2, 4, 6, 8}
+    entity_id, two = query_two.result()
+    assert set(entity_id.value.numpy()) == {6, 8}
+    assert set(two.y.numpy()) == {60, 80}
+    assert len(world.get_view(One)) == 5
+    assert len(world.get_view(Two)) == 2
+
+
+def respawn_entities(
+    world: xx.World,
+    commands: xx.Commands,
+    query: xx.Query[xx.EntityId],
+) -> None:
+    entity_id = query.result()
+    commands.despawn(entity_id.value.numpy())
+    assert len(world.get_view(One)) == 0
+    (onei, twoi) = commands.spawn((One, Two), 10)
+    world.get_view(One, onei).x.fill(np.arange(10, dtype=np.float32))
+    world.get_view(Two, twoi).y.fill(np.arange(10, dtype=np.float32))
+    assert len(world.get_view(One)) == 10
+    assert len(world.get_view(Two)) == 10
+
+
+def check_stale_entity_ids(
+    world: xx.World,
+    commands: xx.Commands,
+    query: xx.Query[xx.EntityId],
+) -> None:
+    entity_id = query.result()
+    old_entity_ids = entity_id.value.numpy()
+    assert np.all(commands.is_alive(old_entity_ids))
+    commands.despawn(old_entity_ids[:3])
+    (entity_idi,) = commands.spawn((xx.EntityId,), 3)
+    new_entity_ids = world.get_view(xx.EntityId, entity_idi).value.numpy()
+    assert set(new_entity_ids).isdisjoint(old_entity_ids)
+    assert np.all(commands.is_alive(new_entity_ids))
+    assert not np.any(commands.is_alive(old_entity_ids[:3]))
+    assert np.all(commands.is_alive(old_entity_ids[3:]))
+    with pytest.raises(KeyError):
+        commands.despawn(old_entity_ids[:1])
+
+
+def respawn_one_entity_many_times(
+    world: xx.World,
+    commands: xx.Commands,
+) -> None:
+    entity_ids = np.zeros(300, dtype=np.uint32)
+    for i in range(len(entity_ids)):
+        (entity_idi,) = commands.spawn((xx.EntityId,), 1)
+        entity_ids[i] = world.get_view(xx.EntityId, entity_idi).value.get(0)
+        commands.despawn(entity_ids[i : i + 1])
+    assert len(set(entity_ids)) == len(entity_ids)
+    assert not np.any(commands.is_alive(entity_ids))
+    # The first slot is used once for each of its 256 generations, and
+    # then retired.
+    assert np.count_nonzero(entity_ids & 0xFFFFFF == 0) == 256
+
+
+def spawn_entities(world: xx.World, commands: xx.Commands) -> None:
+    (onei,) = commands.spawn((One,), 5)
+    world.get_view(One, onei).x.fill([0, 1, 2, 3, 4])
+    (onei, twoi) = commands.spawn((One, Two), 5)
+    world.get_view(One, onei).x.fill([5, 6, 7, 8, 9])
+    world.get_view(Two, twoi).y.fill([50, 60, 70, 80, 90])
+
+
+@pytest.fixture
+def app() -> xx.SimulationApp:
+    app = xx.SimulationApp(num_entities=10)
+    app.add_pool(One.create_pool(10))
+    app.add_pool(Two.create_pool(10))
+    app.add_startup_system(spawn_entities)
+    return app
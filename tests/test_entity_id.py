@@ -14,6 +14,21 @@ def test_entity_id(app: xx.RealTimeApp) -> None:
     app.update()
 
 
+def test_spawning_repeated_component_raises() -> None:
+    app = xx.RealTimeApp(num_entities=10)
+    app.add_pool(One.create_pool(10))
+    app.add_startup_system(spawn_repeated_component)
+    app.update()
+
+
+def spawn_repeated_component(world: xx.World, commands: xx.Commands) -> None:
+    commands.spawn((One,), 6)
+    with pytest.raises(ValueError, match="more than once"):
+        commands.spawn((One, One), 3)
+    assert len(world.get_view(One)) == 6
+    assert len(world.get_view(xx.EntityId)) == 6
+
+
 def system1(query: xx.Query[tuple[xx.EntityId, One]]) -> None:
     entity_id, _ = query.result()
     assert set(entity_id.value.numpy()) == {*range(5), *range(10, 15)}
@@ -0,0 +1,74 @@
+import numpy as np
+import pytest
+import xecs as xx
+
+
+class Health(xx.Component):
+    value: xx.Float32 = xx.float32(default=10)
+    name: xx.PyField[str] = xx.py_field(default="unnamed")
+
+
+def test_fixed_pool_raises_when_full() -> None:
+    app = xx.SimulationApp(num_entities=10)
+    app.add_pool(Health.create_pool(2))
+    app.add_startup_system(spawn_three)
+    with pytest.raises(RuntimeError):
+        app.update(xx.Duration.from_millis(1))
+
+
+def test_growable_pool_grows() -> None:
+    app = xx.SimulationApp(num_entities=2, growable=True)
+    app.add_pool(Health.create_pool(2, growable=True))
+    app.add_startup_system(spawn_three)
+    app.add_system(check_grown)
+    app.update(xx.Duration.from_millis(1))
+
+
+def test_views_remain_valid_after_growing() -> None:
+    app = xx.SimulationApp(num_entities=2, growable=True)
+    app.add_pool(Health.create_pool(2, growable=True, growth_factor=1.5))
+    app.add_startup_system(spawn_with_old_view)
+    app.update(xx.Duration.from_millis(1))
+
+
+def test_growable_pool_stops_at_max_capacity() -> None:
+    app = xx.SimulationApp(num_entities=2, growable=True)
+    app.add_pool(Health.create_pool(2, growable=True, max_capacity=4))
+    app.add_startup_system(spawn_three)
+    app.add_system(spawn_one)
+    app.update(xx.Duration.from_millis(1))
+    with pytest.raises(RuntimeError):
+        app.update(xx.Duration.from_millis(1))
+
+
+def test_invalid_growth_policy_raises() -> None:
+    with pytest.raises(ValueError):
+        Health.create_pool(2, growable=True, growth_factor=0.5)
+    with pytest.raises(ValueError):
+        Health.create_pool(2, growable=True, max_capacity=1)
+
+
+def spawn_one(commands: xx.Commands) -> None:
+    commands.spawn((Health,), 1)
+
+
+def spawn_three(commands: xx.Commands) -> None:
+    commands.spawn((Health,), 3)
+
+
+def check_grown(query: xx.Query[Health]) -> None:
+    health = query.result()
+    assert len(health) == 3
+    assert np.all(health.value.numpy() == 10)
+    assert [health.name.get(i) for i in range(3)] == ["unnamed"] * 3
+
+
+def spawn_with_old_view(world: xx.World, commands: xx.Commands) -> None:
+    (healthi,) = commands.spawn((Health,), 2)
+    old_view = world.get_view(Health, healthi)
+    old_view.value.fill([1, 2])
+    (new_healthi,) = commands.spawn((Health,), 4)
+    world.get_view(Health, new_healthi).value.fill([3, 4, 5, 6])
+    assert np.array_equal(old_view.value.numpy(), [1, 2])
+    old_view.value += 1
+    assert sorted(world.get_view(Health).value.numpy()) == [2, 3, 3, 4, 5, 6]
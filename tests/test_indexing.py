@@ -0,0 +1,150 @@
+import typing
+
+import numpy as np
+import pytest
+import xecs as xx
+
+KEYS = (
+    slice(1, 4),
+    slice(None, None, -2),
+    slice(-2, None),
+    slice(5, 1),
+    np.array([4, 0, 0, -1]),
+    np.array([2, 1], dtype=np.int32),
+    np.array([1, 3], dtype=np.uint8),
+    np.array([-1, 0], dtype=np.int16),
+    np.array([4], dtype=np.uint64),
+    [3, -5],
+    [],
+    [True, False, True, False, True],
+)
+
+
+@pytest.mark.parametrize("key", KEYS)
+def test_getitem_matches_numpy(
+    column: typing.Any,
+    dtype: typing.Any,
+    key: typing.Any,
+) -> None:
+    values = np.arange(5, dtype=dtype)
+    view = column.p_from_numpy(values)[key]
+    assert np.array_equal(view.numpy(), values[key])
+
+
+def test_integer_selects_one_element(
+    column: typing.Any,
+    dtype: typing.Any,
+) -> None:
+    xs = column.p_from_numpy(np.arange(5, dtype=dtype))
+    assert np.array_equal(xs[-2].numpy(), [3])
+    with pytest.raises(IndexError):
+        xs[5]
+    with pytest.raises(IndexError):
+        xs[np.array([0, -6])]
+
+
+@pytest.mark.parametrize(
+    ("key", "expected"),
+    (
+        (slice(None, None, 2), [9, 1, 8, 3, 7]),
+        (np.array([4, 0, 2]), [8, 1, 7, 3, 9]),
+        ([-1, 0], [8, 1, 2, 3, 9]),
+    ),
+)
+def test_setitem_lines_up_with_selection(
+    column: typing.Any,
+    dtype: typing.Any,
+    key: typing.Any,
+    expected: list[int],
+) -> None:
+    xs = column.p_from_numpy(np.arange(5, dtype=dtype))
+    xs[key] = np.array([9, 8, 7], dtype=dtype)[: len(xs[key])]
+    assert np.array_equal(xs.numpy(), expected)
+
+
+def test_setitem_with_mask_lines_up_with_selection(
+    column: typing.Any,
+    dtype: typing.Any,
+) -> None:
+    xs = column.p_from_numpy(np.arange(5, dtype=dtype))
+    xs[np.array([True, False, False, True, False])] = [5, 6]
+    assert np.array_equal(xs.numpy(), [5, 1, 2, 6, 4])
+    xs[[False, True, False, False, False]] = [7]
+    assert np.array_equal(xs.numpy(), [5, 7, 2, 6, 4])
+    xs[np.array([False, False, True, False, True])] = 9
+    assert np.array_equal(xs.numpy(), [5, 7, 9, 6, 9])
+    with pytest.raises(ValueError, match="2 values .* got 5"):
+        xs[np.array([True, False, False, True, False])] = [1, 2, 3, 4, 5]
+
+
+def test_views_of_views_write_through(
+    column: typing.Any,
+    dtype: typing.Any,
+) -> None:
+    xs = column.p_from_numpy(np.zeros(10, dtype=dtype))
+    xs[2:8][::-1][np.array([0, 2])] = 1
+    assert np.array_equal(np.flatnonzero(xs.numpy()), [5, 7])
+
+
+def test_bool_indexing() -> None:
+    values = np.array([True, False, False, True, True])
+    xs = xx.Bool.p_from_numpy(values)
+    assert np.array_equal(xs[1:].numpy(), values[1:])
+    assert np.array_equal(xs[[4, 1]].numpy(), values[[4, 1]])
+    xs[::2] = [False, True, False]
+    assert np.array_equal(xs.numpy(), [False, False, True, True, False])
+    xs[-1] = True
+    assert xs.get(-1)
+    xs[np.array([True, True, False, False, False])] = [True, False]
+    assert np.array_equal(xs.numpy(), [True, False, True, True, True])
+
+
+def test_float_index_array_is_rejected() -> None:
+    xs = xx.Int32.p_from_numpy(np.arange(5, dtype=np.int32))
+    with pytest.raises(TypeError):
+        xs[np.array([1.0, 2.0])]
+
+
+def test_array_view_indices() -> None:
+    indices = xx.ArrayViewIndices.with_capacity(6)
+    indices.spawn(6)
+    assert len(indices[1:5][[0, -1]]) == 2
+    assert len(indices[np.arange(6) % 2 == 0]) == 3
+    with pytest.raises(ValueError, match="6 values .* got 2"):
+        indices[np.array([True, False])]
+
+
+class Position(xx.Struct):
+    x: xx.Float32
+    y: xx.Float32
+
+
+class Unit(xx.Component):
+    position: Position
+    hp: xx.Int32
+    name: xx.PyField[str] = xx.py_field(default="unit")
+
+
+def test_component_indexing() -> None:
+    app = xx.RealTimeApp(num_entities=6)
+    app.add_pool(Unit.create_pool(6))
+    app.add_startup_system(spawn_units)
+    app.add_system(check_component_indexing)
+    app.update()
+
+
+def spawn_units(commands: xx.Commands) -> None:
+    commands.spawn((Unit,), 6)
+
+
+def check_component_indexing(query: xx.Query[Unit]) -> None:
+    units = query.result()
+    units.hp.fill(np.arange(6, dtype=np.int32))
+    top = units[np.argsort(units.hp.numpy())[::-1][:2]]
+    assert np.array_equal(top.hp.numpy(), [5, 4])
+    top.position.x.fill(1)
+    top.name[0] = "leader"
+    assert np.array_equal(np.flatnonzero(units.position.x.numpy()), [4, 5])
+    assert units.name.get(5) == "leader"
+    assert units[1::2].name[-1].get(0) == "leader"
+    assert np.array_equal(units.position[3:5].x.numpy(), [0, 1])
@@ -0,0 +1,141 @@
+import numpy as np
+import pytest
+import xecs as xx
+
+
+class Health(xx.Component):
+    value: xx.Float32
+
+
+class Stunned(xx.Component):
+    time_left: xx.Float32
+
+
+def test_inserted_components_are_queried(app: xx.SimulationApp) -> None:
+    app.add_system(stun_first_entities)
+    app.update(xx.Duration.from_millis(1))
+    app.add_system(check_first_entities_stunned)
+    app.update(xx.Duration.from_millis(1))
+
+
+def test_removed_components_are_not_queried(app: xx.SimulationApp) -> None:
+    app.add_system(stun_first_entities)
+    app.update(xx.Duration.from_millis(1))
+    app.add_system(unstun_entities)
+    app.update(xx.Duration.from_millis(1))
+    app.add_system(check_no_entities_stunned)
+    app.update(xx.Duration.from_millis(1))
+
+
+def test_inserting_existing_component_keeps_it(
+    app: xx.SimulationApp,
+) -> None:
+    app.add_system(stun_first_entities)
+    app.update(xx.Duration.from_millis(1))
+    app.add_system(restun_entities)
+    app.update(xx.Duration.from_millis(1))
+
+
+def test_inserting_repeated_component_raises(
+    app: xx.SimulationApp,
+) -> None:
+    app.add_system(stun_first_entities)
+    app.update(xx.Duration.from_millis(1))
+    app.add_system(insert_repeated_component)
+    app.update(xx.Duration.from_millis(1))
+
+
+def test_entity_id_cannot_be_removed(app: xx.SimulationApp) -> None:
+    app.add_system(remove_entity_id)
+    app.update(xx.Duration.from_millis(1))
+
+
+def stun_first_entities(
+    world: xx.World,
+    commands: xx.Commands,
+    query: xx.Query[tuple[xx.EntityId, Health]],
+) -> None:
+    entity_id, health = query.result()
+    first = entity_id[health.value.numpy() < 2]
+    (stunnedi,) = commands.insert_components(
+        first.value.numpy(), (Stunned,)
+    )
+    world.get_view(Stunned, stunnedi).time_left.fill(5)
+
+
+def check_first_entities_stunned(
+    query: xx.Query[tuple[Health, Stunned]],
+) -> None:
+    health, stunned = query.result()
+    assert sorted(health.value.numpy()) == [0, 1]
+    assert np.all(stunned.time_left.numpy() == 5)
+
+
+def unstun_entities(
+    commands: xx.Commands,
+    query: xx.Query[tuple[xx.EntityId, Stunned]],
+) -> None:
+    entity_id, _ = query.result()
+    commands.remove_components(entity_id.value.numpy(), (Stunned,))
+
+
+def check_no_entities_stunned(
+    world: xx.World,
+    health_query: xx.Query[Health],
+    stunned_query: xx.Query[tuple[Health, Stunned]],
+) -> None:
+    assert len(health_query.result()) == 5
+    health, _ = stunned_query.result()
+    assert len(health) == 0
+    assert len(world.get_view(Stunned)) == 0
+
+
+def restun_entities(
+    world: xx.World,
+    commands: xx.Commands,
+    query: xx.Query[xx.EntityId],
+) -> None:
+    entity_id = query.result()
+    (stunnedi,) = commands.insert_components(
+        entity_id.value.numpy(), (Stunned,)
+    )
+    stunned = world.get_view(Stunned, stunnedi)
+    assert len(stunned) == 5
+    assert sorted(stunned.time_left.numpy()) == [0, 0, 0, 5, 5]
+    assert len(world.get_view(Stunned)) == 5
+
+
+def insert_repeated_component(
+    world: xx.World,
+    commands: xx.Commands,
+    query: xx.Query[xx.EntityId],
+) -> None:
+    entity_id = query.result()
+    with pytest.raises(ValueError, match="more than once"):
+        commands.insert_components(
+            entity_id.value.numpy(), (Stunned, Stunned)
+        )
+    assert len(world.get_view(Stunned)) == 2
+
+
+def remove_entity_id(
+    commands: xx.Commands,
+    query: xx.Query[xx.EntityId],
+) -> None:
+    entity_id = query.result()
+    with pytest.raises(ValueError):
+        commands.remove_components(entity_id.value.numpy(), (xx.EntityId,))
+
+
+def spawn_entities(world: xx.World, commands: xx.Commands) -> None:
+    (healthi,) = commands.spawn((Health,), 5)
+    world.get_view(Health, healthi).value.fill([0, 1, 2, 3, 4])
+
+
+@pytest.fixture
+def app() -> xx.SimulationApp:
+    app = xx.SimulationApp(num_entities=5)
+    app.add_pool(Health.create_pool(5))
+    app.add_pool(Stunned.create_pool(5))
+    app.add_startup_system(spawn_entities)
+    return app
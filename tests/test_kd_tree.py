@@ -0,0 +1,118 @@
+import typing
+
+import numpy as np
+import pytest
+import xecs as xx
+
+X = [0, 1, 1, 2.5, 3, -4, -4.5, 10, 7]
+Y = [0, 0, 1.3, 2.5, -3, -4, -3, 10, 8]
+
+
+class Point(xx.Component):
+    position: xx.Vec2
+    label: xx.Int32
+
+
+def test_nearest(app: xx.RealTimeApp) -> None:
+    app.add_system(check_nearest)
+    app.update()
+
+
+def check_nearest(query: xx.Query[Point]) -> None:
+    point = query.result()
+    labels = point.label.numpy()
+    distances = pairwise_distances(point.position, point.position)
+    np.fill_diagonal(distances, np.inf)
+    for k in (0, 1, 3, 8):
+        point1, point2, distance = query.nearest(point.position, k)
+        assert np.array_equal(point1.label.numpy(), np.repeat(labels, k))
+        expected = np.argsort(distances, axis=1, kind="stable")[:, :k]
+        assert np.array_equal(
+            point2.label.numpy(), labels[expected].ravel()
+        )
+        assert np.allclose(
+            distance, np.take_along_axis(distances, expected, 1).ravel()
+        )
+    with pytest.raises(ValueError, match="k must be less"):
+        query.nearest(point.position, 9)
+
+
+def test_nearest_to(app: xx.RealTimeApp) -> None:
+    app.add_system(check_nearest_to)
+    app.update()
+
+
+def check_nearest_to(query: xx.Query[tuple[Point, xx.Transform2]]) -> None:
+    point, transform = query.result()
+    transform.translation.x.fill(point.position.x)
+    transform.translation.y.fill(point.position.y)
+    probes = xx.Vec2.from_numpy(
+        np.array([[0.2, 9, -10, 2.5], [0.1, 9, -10, 2.5]], dtype=np.float32)
+    )
+    labels = point.label.numpy()
+    distances = pairwise_distances(probes, point.position)
+    for k in (0, 2, 9):
+        (neighbor, neighbor_transform), distance = query.nearest_to(
+            (point.position.x, point.position.y), probes, k
+        )
+        expected = np.argsort(distances, axis=1, kind="stable")[:, :k]
+        assert np.array_equal(
+            neighbor.label.numpy(), labels[expected].ravel()
+        )
+        assert np.array_equal(
+            neighbor_transform.translation.x.numpy(),
+            neighbor.position.x.numpy(),
+        )
+        assert np.allclose(
+            distance, np.take_along_axis(distances, expected, 1).ravel()
+        )
+    with pytest.raises(ValueError, match="k must be at most"):
+        query.nearest_to(transform.translation, probes, 10)
+
+
+def test_kd_tree_errors() -> None:
+    xs = xx.Float32.p_from_numpy(np.arange(3, dtype=np.float32))
+    ys = xx.Float32.p_from_numpy(np.arange(2, dtype=np.float32))
+    with pytest.raises(ValueError, match="3 values .* got 2"):
+        xx.KdTree(xs, ys)
+    nans = xx.Float32.p_from_numpy(np.array([0, np.nan, 1], np.float32))
+    with pytest.raises(ValueError, match="finite"):
+        xx.KdTree(xs, nans)
+    tree = xx.KdTree(xs, xs)
+    assert len(tree) == 3
+    with pytest.raises(ValueError, match="finite"):
+        tree.nearest_to(xs, nans, 1, [])
+    indices = xx.ArrayViewIndices.with_capacity(2)
+    indices.spawn(2)
+    with pytest.raises(ValueError, match="3 values .* got 2"):
+        tree.nearest(1, [indices])
+    neighbors, distances = tree.nearest_to(ys, ys, 3, [])
+    assert neighbors == []
+    assert len(distances) == 6
+
+
+def pairwise_distances(
+    points1: typing.Any,
+    points2: typing.Any,
+) -> typing.Any:
+    return np.hypot(
+        np.subtract.outer(points1.x.numpy(), points2.x.numpy()),
+        np.subtract.outer(points1.y.numpy(), points2.y.numpy()),
+    )
+
+
+def spawn_entities(world: xx.World, commands: xx.Commands) -> None:
+    pointi, _ = commands.spawn((Point, xx.Transform2), len(X))
+    point = world.get_view(Point, pointi)
+    point.label.fill(np.arange(len(X), dtype=np.int32))
+    point.position.x.fill(X)
+    point.position.y.fill(Y)
+
+
+@pytest.fixture
+def app() -> xx.RealTimeApp:
+    app = xx.RealTimeApp(num_entities=10)
+    app.add_pool(Point.create_pool(10))
+    app.add_pool(xx.Transform2.create_pool(10))
+    app.add_startup_system(spawn_entities)
+    return app
@@ -0,0 +1,127 @@
+import typing
+
+import numpy as np
+import pytest
+import xecs as xx
+
+
+@pytest.mark.parametrize(
+    ("name", "function"),
+    (
+        ("sqrt", np.sqrt),
+        ("exp", np.exp),
+        ("log", np.log),
+        ("sin", np.sin),
+        ("cos", np.cos),
+        ("floor", np.floor),
+        ("ceil", np.ceil),
+    ),
+)
+def test_float_function_matches_numpy(
+    column: typing.Any,
+    dtype: typing.Any,
+    name: str,
+    function: typing.Any,
+) -> None:
+    values = np.array([0.5, 1.5, 2.25, 3.75], dtype=dtype)
+    expected = function(values)
+    xs = column.p_from_numpy(values)
+    assert np.allclose(getattr(xs, name)(), expected)
+    assert np.array_equal(xs.numpy(), values)
+    getattr(xs, f"{name}_in_place")()
+    assert np.allclose(xs.numpy(), expected)
+
+
+def test_float_unary_operators(column: typing.Any, dtype: typing.Any) -> None:
+    values = np.array([-1.5, 0.5, 2.5], dtype=dtype)
+    xs = column.p_from_numpy(values)
+    assert np.array_equal(-xs, -values)
+    assert np.array_equal(abs(xs), np.abs(values))
+    assert np.array_equal(round(xs), [-2, 0, 2])
+    xs.neg_in_place()
+    assert np.array_equal(xs.numpy(), -values)
+    xs.abs_in_place()
+    assert np.array_equal(xs.numpy(), np.abs(values))
+
+
+def test_round_to_decimal_places(
+    column: typing.Any,
+    dtype: typing.Any,
+) -> None:
+    values = np.array([1.234, -5.678], dtype=dtype)
+    xs = column.p_from_numpy(values)
+    assert np.allclose(round(xs, 1), np.round(values, 1))
+    xs.round_in_place(2)
+    assert np.allclose(xs.numpy(), np.round(values, 2))
+
+
+def test_atan2(column: typing.Any, dtype: typing.Any) -> None:
+    ys = np.array([1, -1, 0, 1], dtype=dtype)
+    xs = np.array([1, 1, -1, 0], dtype=dtype)
+    column_ys = column.p_from_numpy(ys)
+    expected = np.arctan2(ys, xs)
+    assert np.allclose(column_ys.atan2(column.p_from_numpy(xs)), expected)
+    assert np.allclose(column_ys.atan2(xs), expected)
+    column_ys.atan2_in_place(xs.tolist())
+    assert np.allclose(column_ys.numpy(), expected)
+
+
+def test_in_place_function_only_changes_view(
+    column: typing.Any,
+    dtype: typing.Any,
+) -> None:
+    xs = column.p_from_numpy(np.array([4, 9, 16], dtype=dtype))
+    xs[np.array([False, True, True])].sqrt_in_place()
+    assert np.array_equal(xs.numpy(), [4, 3, 4])
+
+
+@pytest.mark.parametrize(
+    ("column", "dtype"),
+    (
+        (xx.Float32, np.float32),
+        (xx.Int8, np.int8),
+        (xx.Int32, np.int32),
+        (xx.UInt32, np.uint32),
+    ),
+)
+def test_clamp(column: typing.Any, dtype: typing.Any) -> None:
+    values = np.array([0, 3, 5, 8, 10], dtype=dtype)
+    xs = column.p_from_numpy(values)
+    assert np.array_equal(xs.clamp(3, 8), np.clip(values, 3, 8))
+    xs.clamp_in_place(4, 6)
+    assert np.array_equal(xs.numpy(), np.clip(values, 4, 6))
+
+
+@pytest.mark.parametrize(
+    ("column", "dtype"),
+    (
+        (xx.Int8, np.int8),
+        (xx.Int16, np.int16),
+        (xx.Int32, np.int32),
+        (xx.Int64, np.int64),
+    ),
+)
+def test_signed_integer_unary_operators(
+    column: typing.Any,
+    dtype: typing.Any,
+) -> None:
+    values = np.array([-3, 0, 4], dtype=dtype)
+    xs = column.p_from_numpy(values)
+    assert np.array_equal(-xs, -values)
+    assert np.array_equal(abs(xs), np.abs(values))
+    xs.neg_in_place()
+    assert np.array_equal(xs.numpy(), -values)
+    xs.abs_in_place()
+    assert np.array_equal(xs.numpy(), np.abs(values))
+
+
+# The math functions are only on float columns, so this narrows the
+# columns of the shared fixture in conftest.py.
+@pytest.fixture(
+    params=((xx.Float32, np.float32), (xx.Float64, np.float64)),
+    ids=("Float32", "Float64"),
+)
+def column_and_dtype(
+    request: pytest.FixtureRequest,
+) -> tuple[typing.Any, typing.Any]:
+    return request.param
@@ -0,0 +1,157 @@
+import operator
+import typing
+
+import numpy as np
+import numpy.typing as npt
+import pytest
+import xecs as xx
+
+
+def test_operator(
+    column: typing.Any,
+    dtype: typing.Any,
+    op: typing.Any,
+) -> None:
+    xs = column.p_from_numpy(lhs(dtype))
+    expected = op(lhs(dtype), rhs(dtype))
+    assert np.array_equal(op(xs, column.p_from_numpy(rhs(dtype))), expected)
+    assert np.array_equal(op(xs, rhs(dtype)), expected)
+    assert np.array_equal(op(xs, rhs(dtype).tolist()), expected)
+    assert np.array_equal(op(xs, 2), op(lhs(dtype), dtype(2)))
+    ys = column.p_from_numpy(rhs(dtype))
+    assert np.array_equal(op(ys, ys), op(rhs(dtype), rhs(dtype)))
+
+
+def test_in_place_operator(
+    column: typing.Any,
+    dtype: typing.Any,
+    iop: typing.Any,
+) -> None:
+    expected = iop(lhs(dtype), rhs(dtype))
+    xs = column.p_from_numpy(lhs(dtype))
+    iop(xs, column.p_from_numpy(rhs(dtype)))
+    assert np.array_equal(xs.numpy(), expected)
+    xs = column.p_from_numpy(lhs(dtype))
+    iop(xs, rhs(dtype))
+    assert np.array_equal(xs.numpy(), expected)
+    xs = column.p_from_numpy(lhs(dtype))
+    iop(xs, rhs(dtype).tolist())
+    assert np.array_equal(xs.numpy(), expected)
+
+
+def test_in_place_operator_with_self(
+    column: typing.Any,
+    dtype: typing.Any,
+    iop: typing.Any,
+) -> None:
+    expected = iop(lhs(dtype), rhs(dtype))
+    xs = column.p_from_numpy(np.concatenate([lhs(dtype), rhs(dtype)]))
+    first = np.arange(10) < 5
+    iop(xs[first], xs[~first])
+    assert np.array_equal(xs.numpy()[:5], expected)
+
+
+def test_fill_from_view_of_same_array(
+    column: typing.Any,
+    dtype: typing.Any,
+) -> None:
+    xs = column.p_from_numpy(lhs(dtype))
+    first = np.array([True, True, False, False, False])
+    last = np.array([False, False, False, True, True])
+    xs[first].fill(xs[last])
+    assert np.array_equal(xs.numpy(), [9, 10, 8, 9, 10])
+
+
+def test_setitem(column: typing.Any, dtype: typing.Any) -> None:
+    xs = column.p_from_numpy(lhs(dtype))
+    xs[xs.numpy() > 7] = 1
+    assert np.array_equal(xs.numpy(), [6, 7, 1, 1, 1])
+
+
+def test_get(column: typing.Any, dtype: typing.Any) -> None:
+    xs = column.p_from_numpy(lhs(dtype))[np.array([0, 1, 0, 1, 1], bool)]
+    assert xs.get(0) == 7
+    assert xs.get(2) == 10
+    assert xs.get(-1) == 10
+    assert xs.get(-3) == 7
+    for index in (3, -4, 2**40):
+        with pytest.raises(IndexError, match="length 3"):
+            xs.get(index)
+
+
+def test_length_mismatch(column: typing.Any, dtype: typing.Any) -> None:
+    xs = column.p_from_numpy(lhs(dtype))
+    short = np.ones(4, dtype=dtype)
+    with pytest.raises(ValueError, match="5 values .* got 4"):
+        xs.fill(short)
+    with pytest.raises(ValueError, match="5 values .* got 6"):
+        xs.fill([1] * 6)
+    with pytest.raises(ValueError, match="5 values .* got 4"):
+        xs.fill(column.p_from_numpy(short))
+    with pytest.raises(ValueError, match="5 values .* got 4"):
+        xs[np.ones(5, dtype=np.bool_)] = short
+    with pytest.raises(ValueError, match="5 values .* got 3"):
+        xs[np.ones(3, dtype=np.bool_)] = 1
+    with pytest.raises(ValueError, match="5 values .* got 4"):
+        xs + short
+    with pytest.raises(ValueError, match="5 values .* got 4"):
+        xs += short
+    assert np.array_equal(xs.numpy(), lhs(dtype))
+
+
+def test_repr(column: typing.Any, dtype: typing.Any) -> None:
+    xs = column.p_from_numpy(np.array([1], dtype=dtype))
+    value = "1.0" if np.issubdtype(dtype, np.floating) else "1"
+    assert xs.to_str() == f"<xecs.{column.__name__} [{value}]>"
+    assert repr(xs) == f"<xecs.{column.__name__} [\n    {value},\n]>"
+
+
+def test_default_value(column: typing.Any, dtype: typing.Any) -> None:
+    assert column.p_default_value() == 0
+    indices = xx.ArrayViewIndices.with_capacity(3)
+    xs = column.p_from_indices(indices, 5)
+    indices.spawn(3)
+    assert np.array_equal(xs.numpy(), [5, 5, 5])
+    assert xs.numpy().dtype == dtype
+
+
+def lhs(dtype: typing.Any) -> npt.NDArray[typing.Any]:
+    return np.arange(6, 11, dtype=dtype)
+
+
+def rhs(dtype: typing.Any) -> npt.NDArray[typing.Any]:
+    return np.array([1, 2, 1, 2, 1], dtype=dtype)
+
+
+@pytest.fixture(
+    params=(
+        operator.add,
+        operator.sub,
+        operator.mul,
+        operator.floordiv,
+        operator.mod,
+        operator.pow,
+        operator.lt,
+        operator.le,
+        operator.gt,
+        operator.ge,
+        operator.eq,
+        operator.ne,
+    ),
+)
+def op(request: pytest.FixtureRequest) -> typing.Any:
+    return request.param
+
+
+@pytest.fixture(
+    params=(
+        operator.iadd,
+        operator.isub,
+        operator.imul,
+        operator.ifloordiv,
+        operator.imod,
+        operator.ipow,
+    ),
+)
+def iop(request: pytest.FixtureRequest) -> typing.Any:
+    return request.param
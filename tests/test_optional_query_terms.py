@@ -0,0 +1,157 @@
+import numpy as np
+import pytest
+import xecs as xx
+from xecs._internal.systems import SystemSignatureError
+
+
+class Position(xx.Component):
+    x: xx.Float32
+
+
+class Sprite(xx.Component):
+    size: xx.Float32
+
+
+class Frozen(xx.Component):
+    pass
+
+
+def test_optional_term_gives_mask_and_view(app: xx.SimulationApp) -> None:
+    app.add_system(check_optional_sprite)
+    app.update(xx.Duration.from_millis(1))
+
+
+def test_optional_term_as_single_component(app: xx.SimulationApp) -> None:
+    app.add_system(check_optional_only_in_tuple)
+    app.update(xx.Duration.from_millis(1))
+
+
+def test_optional_term_with_filter(app: xx.SimulationApp) -> None:
+    app.add_system(check_optional_sprite_without_frozen)
+    app.update(xx.Duration.from_millis(1))
+
+
+def test_optional_term_tracks_inserted_components(
+    app: xx.SimulationApp,
+) -> None:
+    app.add_system(add_sprite_to_all)
+    app.update(xx.Duration.from_millis(1))
+    app.add_system(check_all_have_sprite)
+    app.update(xx.Duration.from_millis(1))
+
+
+def test_query_with_only_optional_terms_raises(
+    app: xx.SimulationApp,
+) -> None:
+    app.add_system(query_only_optional)
+    with pytest.raises(SystemSignatureError):
+        app.update(xx.Duration.from_millis(1))
+
+
+def test_pairing_entities_with_optional_term_raises(
+    app: xx.SimulationApp,
+) -> None:
+    app.add_system(pair_with_optional_sprite)
+    app.update(xx.Duration.from_millis(1))
+
+
+def pair_with_optional_sprite(
+    query: xx.Query[tuple[Position, xx.Maybe[Sprite]]],
+    positions: xx.Query[Position],
+) -> None:
+    pairings = (
+        query.product_2,
+        query.combinations_2,
+        lambda: query.product_with(positions),
+        lambda: positions.product_with(query),
+        lambda: query.product_2_chunks(4),
+        lambda: query.combinations_2_chunks(4),
+        lambda: query.product_with_chunks(positions, 4),
+    )
+    for pairing in pairings:
+        with pytest.raises(TypeError, match="has a Maybe term"):
+            pairing()
+    position, _ = query.result()
+    xy = (position.x, position.x)
+    with pytest.raises(TypeError, match="Maybe"):
+        query.pairs_within(xy, 1)
+    with pytest.raises(TypeError, match="Maybe"):
+        query.nearest(xy, 1)
+    with pytest.raises(TypeError, match="Maybe"):
+        query.nearest_to(xy, xy, 1)
+
+
+def check_optional_sprite(
+    query: xx.Query[tuple[Position, xx.Maybe[Sprite]]],
+) -> None:
+    position, (has_sprite, sprite) = query.result()
+    assert len(position) == 6
+    assert len(has_sprite) == 6
+    assert has_sprite.numpy().sum() == 3
+    assert np.array_equal(
+        position[has_sprite.numpy()].x.numpy() * 10,
+        sprite.size.numpy(),
+    )
+    assert sorted(position[~has_sprite.numpy()].x.numpy()) == [0, 2, 4]
+
+
+def check_optional_only_in_tuple(
+    query: xx.Query[tuple[xx.Maybe[Sprite], Position]],
+) -> None:
+    (has_sprite, sprite), position = query.result()
+    assert len(position) == 6
+    assert sorted(sprite.size.numpy()) == [10, 30, 50]
+    assert sorted(position[has_sprite.numpy()].x.numpy()) == [1, 3, 5]
+
+
+def check_optional_sprite_without_frozen(
+    query: xx.Query[tuple[Position, xx.Maybe[Sprite]], xx.Without[Frozen]],
+) -> None:
+    position, (has_sprite, sprite) = query.result()
+    assert sorted(position.x.numpy()) == [0, 1, 2, 3]
+    assert sorted(position[has_sprite.numpy()].x.numpy()) == [1, 3]
+    assert sorted(sprite.size.numpy()) == [10, 30]
+
+
+def add_sprite_to_all(
+    commands: xx.Commands,
+    query: xx.Query[tuple[xx.EntityId, xx.Maybe[Sprite]]],
+) -> None:
+    entity_id, (has_sprite, _) = query.result()
+    without_sprite = entity_id[~has_sprite.numpy()]
+    commands.insert_components(without_sprite.value.numpy(), (Sprite,))
+
+
+def check_all_have_sprite(
+    query: xx.Query[tuple[Position, xx.Maybe[Sprite]]],
+) -> None:
+    position, (has_sprite, sprite) = query.result()
+    assert np.all(has_sprite.numpy())
+    assert len(sprite) == len(position) == 6
+
+
+def query_only_optional(query: xx.Query[xx.Maybe[Sprite]]) -> None:
+    pass
+
+
+def spawn_entities(world: xx.World, commands: xx.Commands) -> None:
+    for x in range(6):
+        components: tuple[type[xx.Component], ...] = (Position,)
+        if x % 2 == 1:
+            components += (Sprite,)
+        if x >= 4:
+            components += (Frozen,)
+        (positioni, *otheri) = commands.spawn(components, 1)
+        world.get_view(Position, positioni).x.fill(x)
+        if x % 2 == 1:
+            world.get_view(Sprite, otheri[0]).size.fill(x * 10)
+
+
+@pytest.fixture
+def app() -> xx.SimulationApp:
+    app = xx.SimulationApp(num_entities=10)
+    app.add_pool(Position.create_pool(10))
+    app.add_pool(Sprite.create_pool(10))
+    app.add_pool(Frozen.create_pool(10))
+    app.add_startup_system(spawn_entities)
+    return app
@@ -0,0 +1,199 @@
+import operator
+import typing
+
+import numpy as np
+import pytest
+import xecs as xx
+
+INT32_MAX = np.iinfo(np.int32).max
+INT32_MIN = np.iinfo(np.int32).min
+
+
+@pytest.mark.parametrize(
+    ("op", "iop"),
+    (
+        (operator.add, operator.iadd),
+        (operator.sub, operator.isub),
+        (operator.mul, operator.imul),
+    ),
+)
+def test_checked_is_the_default(op: typing.Any, iop: typing.Any) -> None:
+    xs = xx.Int32.p_from_numpy(np.array([1, INT32_MAX], dtype=np.int32))
+    assert xs.overflow() == "checked"
+    rhs = -2 if op is operator.sub else 2
+    with pytest.raises(OverflowError):
+        op(xs, rhs)
+    with pytest.raises(OverflowError):
+        iop(xs, rhs)
+    assert np.array_equal(xs.numpy(), [1, INT32_MAX])
+
+
+def test_wrapping_matches_numpy() -> None:
+    values = np.array([1, INT32_MAX, INT32_MIN], dtype=np.int32)
+    xs = xx.Int32.p_from_numpy(values)
+    xs.set_overflow("wrapping")
+    with np.errstate(over="ignore"):
+        assert np.array_equal(xs + 1, values + np.int32(1))
+        assert np.array_equal(xs * 3, values * np.int32(3))
+        assert np.array_equal(-xs, -values)
+    xs -= 2
+    assert np.array_equal(xs.numpy(), [-1, INT32_MAX - 2, INT32_MAX - 1])
+
+
+def test_saturating_clamps() -> None:
+    values = np.array([1, INT32_MAX, INT32_MIN], dtype=np.int32)
+    xs = xx.Int32.p_from_numpy(values)
+    xs.set_overflow("saturating")
+    assert np.array_equal(xs + 1, [2, INT32_MAX, INT32_MIN + 1])
+    assert np.array_equal(xs - 1, [0, INT32_MAX - 1, INT32_MIN])
+    assert np.array_equal(-xs, [-1, -INT32_MAX, INT32_MAX])
+    assert np.array_equal(abs(xs), [1, INT32_MAX, INT32_MAX])
+    xs *= 2
+    assert np.array_equal(xs.numpy(), [2, INT32_MAX, INT32_MIN])
+
+
+@pytest.mark.parametrize(
+    ("mode", "expected"),
+    (
+        ("wrapping", [255, 0]),
+        ("saturating", [0, 0]),
+    ),
+)
+def test_unsigned_underflow(mode: str, expected: list[int]) -> None:
+    xs = xx.UInt8.p_from_numpy(np.array([0, 1], dtype=np.uint8))
+    with pytest.raises(OverflowError):
+        xs - 1
+    xs.set_overflow(mode)
+    xs -= 1
+    assert np.array_equal(xs.numpy(), expected)
+
+
+def test_power_overflow() -> None:
+    xs = xx.Int16.p_from_numpy(np.array([2, 200], dtype=np.int16))
+    with pytest.raises(OverflowError, match="power"):
+        xs**2
+    xs.set_overflow("saturating")
+    xs **= 2
+    assert np.array_equal(xs.numpy(), [4, np.iinfo(np.int16).max])
+
+
+def test_negating_minimum_is_checked() -> None:
+    xs = xx.Int8.p_from_numpy(np.array([-128, 5], dtype=np.int8))
+    with pytest.raises(OverflowError, match="negation"):
+        -xs
+    with pytest.raises(OverflowError, match="absolute value"):
+        xs.abs_in_place()
+    assert np.array_equal(xs.numpy(), [-128, 5])
+
+
+@pytest.mark.parametrize("mode", ("checked", "wrapping", "saturating"))
+@pytest.mark.parametrize(
+    ("op", "iop"),
+    (
+        (operator.truediv, operator.itruediv),
+        (operator.floordiv, operator.ifloordiv),
+        (operator.mod, operator.imod),
+    ),
+)
+def test_division_by_zero_raises(
+    mode: str,
+    op: typing.Any,
+    iop: typing.Any,
+) -> None:
+    xs = xx.Int32.p_from_numpy(np.array([4, 5], dtype=np.int32))
+    xs.set_overflow(mode)
+    zeros = xx.Int32.p_from_numpy(np.array([1, 0], dtype=np.int32))
+    with pytest.raises(ZeroDivisionError):
+        op(xs, zeros)
+    with pytest.raises(ZeroDivisionError):
+        iop(xs, 0)
+    assert np.array_equal(xs.numpy(), [4, 5])
+
+
+def test_float_division_by_zero_is_infinite() -> None:
+    xs = xx.Float32.p_from_numpy(np.array([1, -1], dtype=np.float32))
+    assert np.array_equal(xs / 0, [np.inf, -np.inf])
+
+
+def test_mode_is_shared_between_views() -> None:
+    xs = xx.Int32.p_from_numpy(np.array([INT32_MAX, 0], dtype=np.int32))
+    view = xs[np.array([True, False])]
+    view.set_overflow("saturating")
+    assert xs.overflow() == "saturating"
+    xs += 1
+    assert np.array_equal(xs.numpy(), [INT32_MAX, 1])
+
+
+def test_narrowing_promoted_result_is_checked() -> None:
+    xs = xx.Int32.p_from_numpy(np.array([INT32_MAX, 0], dtype=np.int32))
+    ys = xx.UInt32.p_from_numpy(np.array([1, 1], dtype=np.uint32))
+    with pytest.raises(OverflowError, match="conversion"):
+        xs += ys
+    xs.set_overflow("saturating")
+    xs += ys
+    assert np.array_equal(xs.numpy(), [INT32_MAX, 1])
+
+
+def test_invalid_mode() -> None:
+    xs = xx.Int32.p_from_value(0, 1)
+    with pytest.raises(ValueError, match="overflow must be"):
+        xs.set_overflow("ignore")
+
+
+class Counter(xx.Component):
+    small: xx.Int8 = xx.int8(default=120, overflow="saturating")
+    count: xx.UInt32 = xx.uint32(default=0, overflow="wrapping")
+    total: xx.Int32 = xx.int32(default=7)
+
+
+class Tally(xx.Struct):
+    value: xx.UInt8 = xx.uint8(default=250, overflow="wrapping")
+
+
+class Tallied(xx.Component):
+    tally: Tally
+
+
+def test_field_overflow_mode(app: xx.RealTimeApp) -> None:
+    app.add_system(check_field_overflow_mode)
+    app.update()
+
+
+def check_field_overflow_mode(
+    query: xx.Query[tuple[Counter, Tallied]],
+) -> None:
+    counter, tallied = query.result()
+    assert counter.small.overflow() == "saturating"
+    assert counter.count.overflow() == "wrapping"
+    assert counter.total.overflow() == "checked"
+    assert tallied.tally.value.overflow() == "wrapping"
+    counter.small += 10
+    assert np.array_equal(counter.small.numpy(), [127, 127])
+    counter.count -= 1
+    assert np.array_equal(counter.count.numpy(), [2**32 - 1] * 2)
+    assert np.array_equal(counter.total.numpy(), [7, 7])
+    tallied.tally.value += 10
+    assert np.array_equal(tallied.tally.value.numpy(), [4, 4])
+
+
+def test_invalid_field_mode() -> None:
+    mode: typing.Any = "ignore"
+
+    class Bad(xx.Component):
+        value: xx.Int16 = xx.int16(default=0, overflow=mode)
+
+    with pytest.raises(ValueError, match="overflow must be"):
+        Bad.create_pool(1)
+
+
+def spawn_counters(commands: xx.Commands) -> None:
+    commands.spawn((Counter, Tallied), 2)
+
+
+@pytest.fixture
+def app() -> xx.RealTimeApp:
+    app = xx.RealTimeApp(num_entities=2)
+    app.add_pool(Counter.create_pool(2))
+    app.add_pool(Tallied.create_pool(2))
+    app.add_startup_system(spawn_counters)
+    return app
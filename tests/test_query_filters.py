@@ -0,0 +1,115 @@
+import pytest
+import xecs as xx
+from xecs._internal.systems import SystemSignatureError
+
+
+class Position(xx.Component):
+    x: xx.Float32
+
+
+class Frozen(xx.Component):
+    pass
+
+
+class Player(xx.Component):
+    score: xx.Float32
+
+
+def test_without_filter(app: xx.SimulationApp) -> None:
+    app.add_system(query_without_frozen)
+    app.update(xx.Duration.from_millis(1))
+
+
+def test_with_filter(app: xx.SimulationApp) -> None:
+    app.add_system(query_with_player)
+    app.update(xx.Duration.from_millis(1))
+
+
+def test_multiple_filters(app: xx.SimulationApp) -> None:
+    app.add_system(query_with_player_without_frozen)
+    app.update(xx.Duration.from_millis(1))
+
+
+def test_filters_are_updated(app: xx.SimulationApp) -> None:
+    app.add_system(freeze_all)
+    app.update(xx.Duration.from_millis(1))
+    app.add_system(check_all_frozen)
+    app.update(xx.Duration.from_millis(1))
+
+
+def test_invalid_filter_raises(app: xx.SimulationApp) -> None:
+    app.add_system(query_with_invalid_filter)
+    with pytest.raises(SystemSignatureError):
+        app.update(xx.Duration.from_millis(1))
+
+
+def query_without_frozen(
+    query: xx.Query[Position, xx.Without[Frozen]],
+) -> None:
+    position = query.result()
+    assert sorted(position.x.numpy()) == [0, 1, 4, 5]
+
+
+def query_with_player(
+    query: xx.Query[tuple[Position], xx.With[Player]],
+) -> None:
+    (position,) = query.result()
+    assert sorted(position.x.numpy()) == [1, 3, 5]
+
+
+def query_with_player_without_frozen(
+    query: xx.Query[Position, xx.With[Player], xx.Without[Frozen]],
+) -> None:
+    position = query.result()
+    assert sorted(position.x.numpy()) == [1, 5]
+
+
+def freeze_all(
+    commands: xx.Commands,
+    query: xx.Query[xx.EntityId, xx.Without[Frozen]],
+) -> None:
+    entity_id = query.result()
+    commands.insert_components(entity_id.value.numpy(), (Frozen,))
+
+
+def check_all_frozen(
+    commands: xx.Commands,
+    frozen_query: xx.Query[xx.EntityId, xx.With[Frozen]],
+    unfrozen_query: xx.Query[xx.EntityId, xx.Without[Frozen]],
+) -> None:
+    assert len(unfrozen_query.result()) == 0
+    entity_id = frozen_query.result()
+    assert len(entity_id) == 6
+    commands.remove_components(entity_id.value.numpy(), (Frozen,))
+    assert len(unfrozen_query.result()) == 0
+
+
+def query_with_invalid_filter(
+    query: xx.Query[Position, Frozen],
+) -> None:
+    pass
+
+
+def spawn_entities(world: xx.World, commands: xx.Commands) -> None:
+    (positioni,) = commands.spawn((Position,), 1)
+    world.get_view(Position, positioni).x.fill([0])
+    (positioni, _) = commands.spawn((Position, Player), 1)
+    world.get_view(Position, positioni).x.fill([1])
+    (positioni, _) = commands.spawn((Position, Frozen), 1)
+    world.get_view(Position, positioni).x.fill([2])
+    (positioni, _, _) = commands.spawn((Position, Player, Frozen), 1)
+    world.get_view(Position, positioni).x.fill([3])
+    (positioni,) = commands.spawn((Position,), 1)
+    world.get_view(Position, positioni).x.fill([4])
+    (positioni, _) = commands.spawn((Position, Player), 1)
+    world.get_view(Position, positioni).x.fill([5])
+
+
+@pytest.fixture
+def app() -> xx.SimulationApp:
+    app = xx.SimulationApp(num_entities=10)
+    app.add_pool(Position.create_pool(10))
+    app.add_pool(Frozen.create_pool(10))
+    app.add_pool(Player.create_pool(10))
+    app.add_startup_system(spawn_entities)
+    return app
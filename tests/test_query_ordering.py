@@ -0,0 +1,79 @@
+import numpy as np
+import numpy.typing as npt
+import xecs as xx
+
+
+class One(xx.Component):
+    x: xx.Float32
+
+
+class Two(xx.Component):
+    y: xx.Float32
+
+
+class Results(xx.Resource):
+    entity_ids: list[npt.NDArray[np.uint32]]
+    xs: list[npt.NDArray[np.float32]]
+    ys: list[npt.NDArray[np.float32]]
+
+
+def test_query_results_are_ordered_by_entity_id() -> None:
+    results = run_app()
+    for entity_ids in results.entity_ids:
+        assert np.all(entity_ids[:-1] < entity_ids[1:])
+
+
+def test_repeated_runs_give_identical_results() -> None:
+    results1 = run_app()
+    results2 = run_app()
+    for results in (results1, results2):
+        assert len(results.entity_ids) == 10
+    for expected, actual in zip(
+        results1.entity_ids, results2.entity_ids, strict=True
+    ):
+        assert np.array_equal(expected, actual)
+    for expected, actual in zip(results1.xs, results2.xs, strict=True):
+        assert np.array_equal(expected, actual)
+    for expected, actual in zip(results1.ys, results2.ys, strict=True):
+        assert np.array_equal(expected, actual)
+
+
+def run_app() -> Results:
+    app = xx.SimulationApp(num_entities=100)
+    app.add_pool(One.create_pool(100))
+    app.add_pool(Two.create_pool(100))
+    app.add_resource(Results([], [], []))
+    app.add_startup_system(spawn_entities)
+    app.add_system(respawn_entities)
+    app.add_system(record_results)
+    app.run(10, xx.Duration.from_millis(1))
+    return app.world.get_resource(Results)
+
+
+def respawn_entities(
+    world: xx.World,
+    commands: xx.Commands,
+    query: xx.Query[tuple[xx.EntityId, One]],
+) -> None:
+    entity_id, one = query.result()
+    entity_ids = entity_id.value.numpy()
+    commands.despawn(entity_ids[one.x.numpy() % 3 == 0])
+    (onei, twoi) = commands.spawn((One, Two), 10)
+    world.get_view(One, onei).x.fill(np.arange(10, dtype=np.float32))
+    world.get_view(Two, twoi).y.fill(np.arange(10, dtype=np.float32))
+
+
+def record_results(
+    results: Results,
+    query: xx.Query[tuple[xx.EntityId, One, Two]],
+) -> None:
+    entity_id, one, two = query.result()
+    results.entity_ids.append(entity_id.value.numpy())
+    results.xs.append(one.x.numpy())
+    results.ys.append(two.y.numpy())
+
+
+def spawn_entities(world: xx.World, commands: xx.Commands) -> None:
+    (onei, twoi) = commands.spawn((One, Two), 50)
+    world.get_view(One, onei).x.fill(np.arange(50, dtype=np.float32))
+    world.get_view(Two, twoi).y.fill(np.arange(50, dtype=np.float32))
@@ -0,0 +1,98 @@
+import math
+import typing
+
+import numpy as np
+import pytest
+import xecs as xx
+
+
+@pytest.mark.parametrize(
+    "name",
+    ("sum", "mean", "min", "max", "argmin", "argmax", "any", "all"),
+)
+def test_reduction_matches_numpy(
+    column: typing.Any,
+    dtype: typing.Any,
+    name: str,
+) -> None:
+    values = np.array([4, 0, 7, 1, 7, 0, 2], dtype=dtype)
+    xs = column.p_from_numpy(values)
+    expected = getattr(np, name)(values)
+    if name == "mean":
+        expected = pytest.approx(expected)
+    assert getattr(xs, name)() == expected
+
+
+def test_reduction_reads_through_view(
+    column: typing.Any,
+    dtype: typing.Any,
+) -> None:
+    values = np.array([9, 3, 1, 5, 8], dtype=dtype)
+    mask = np.array([False, True, True, True, False])
+    view = column.p_from_numpy(values)[mask]
+    assert view.sum() == 9
+    assert view.mean() == pytest.approx(3)
+    assert view.min() == 1
+    assert view.max() == 5
+    assert view.argmin() == 1
+    assert view.argmax() == 2
+    assert view.all()
+
+
+def test_empty_reductions(column: typing.Any, dtype: typing.Any) -> None:
+    xs = column.p_from_numpy(np.array([], dtype=dtype))
+    assert xs.sum() == 0
+    assert math.isnan(xs.mean())
+    assert not xs.any()
+    assert xs.all()
+    for name in ("min", "max", "argmin", "argmax"):
+        with pytest.raises(ValueError, match="empty"):
+            getattr(xs, name)()
+
+
+def test_sum_does_not_overflow() -> None:
+    xs = xx.Int32.p_from_value(2**31 - 1, 4)
+    assert xs.sum() == 4 * (2**31 - 1)
+    assert xs.mean() == 2**31 - 1
+
+
+def test_nan_propagates() -> None:
+    values = np.array([1, np.nan, -1, np.nan], dtype=np.float32)
+    xs = xx.Float32.p_from_numpy(values)
+    assert math.isnan(xs.min())
+    assert math.isnan(xs.max())
+    assert xs.argmin() == np.argmin(values) == 1
+    assert xs.argmax() == np.argmax(values) == 1
+
+
+@pytest.mark.parametrize(
+    "values",
+    (
+        [True, False, True, True],
+        [False, False],
+        [True, True],
+    ),
+)
+@pytest.mark.parametrize(
+    "name",
+    ("sum", "mean", "min", "max", "argmin", "argmax", "any", "all"),
+)
+def test_bool_reduction_matches_numpy(values: list[bool], name: str) -> None:
+    array = np.array(values)
+    xs = xx.Bool.p_from_numpy(array)
+    assert getattr(xs, name)() == getattr(np, name)(array)
+
+
+def test_bool_count_true() -> None:
+    xs = xx.Bool.p_from_numpy(np.array([True, False, True, True, False]))
+    assert xs.count_true() == 3
+    assert xs[np.array([False, True, True, False, True])].count_true() == 1
+
+
+def test_empty_bool_reductions() -> None:
+    xs = xx.Bool.p_from_numpy(np.array([], dtype=np.bool_))
+    assert xs.count_true() == 0
+    assert not xs.any()
+    assert xs.all()
+    with pytest.raises(ValueError, match="empty"):
+        xs.argmax()
@@ -0,0 +1,107 @@
+import numpy as np
+import pytest
+import xecs as xx
+
+
+class Health(xx.Component):
+    value: xx.Float32
+
+
+class Stunned(xx.Component):
+    time_left: xx.Float32
+
+
+class Removed(xx.Resource):
+    entity_ids: list[list[int]]
+
+
+def test_removed_components_are_read(app: xx.SimulationApp) -> None:
+    app.add_system(record_removed_stunned)
+    app.add_system(unstun_first)
+    app.run(3, xx.Duration.from_millis(1))
+    removed = app.world.get_resource(Removed).entity_ids
+    assert len(removed) == 3
+    assert removed[0] == []
+    assert len(removed[1]) == 1
+    assert removed[2] == []
+
+
+def test_despawned_entities_are_read(app: xx.SimulationApp) -> None:
+    app.add_system(record_removed_stunned)
+    app.add_system(despawn_stunned)
+    app.run(3, xx.Duration.from_millis(1))
+    removed = app.world.get_resource(Removed).entity_ids
+    assert removed[0] == []
+    assert len(removed[1]) == 2
+    assert removed[2] == []
+
+
+def test_each_reader_sees_removals(app: xx.SimulationApp) -> None:
+    app.add_system(unstun_first)
+    app.add_system(record_removed_stunned)
+    app.add_system(check_removed_stunned)
+    app.update(xx.Duration.from_millis(1))
+    removed = app.world.get_resource(Removed).entity_ids
+    assert len(removed[0]) == 1
+
+
+def test_removals_of_other_components_are_ignored(
+    app: xx.SimulationApp,
+) -> None:
+    app.add_system(despawn_unstunned)
+    app.add_system(record_removed_stunned)
+    app.update(xx.Duration.from_millis(1))
+    assert app.world.get_resource(Removed).entity_ids == [[]]
+
+
+def record_removed_stunned(
+    removed: Removed,
+    removed_stunned: xx.RemovedComponents[Stunned],
+) -> None:
+    removed.entity_ids.append(removed_stunned.entity_ids.tolist())
+
+
+def check_removed_stunned(
+    removed: Removed,
+    removed_stunned: xx.RemovedComponents[Stunned],
+) -> None:
+    assert np.array_equal(removed_stunned.entity_ids, removed.entity_ids[-1])
+
+
+def unstun_first(
+    commands: xx.Commands,
+    query: xx.Query[tuple[xx.EntityId, Stunned]],
+) -> None:
+    entity_id, stunned = query.result()
+    first = entity_id[stunned.time_left.numpy() == 0]
+    commands.remove_components(first.value.numpy(), (Stunned,))
+
+
+def despawn_stunned(
+    commands: xx.Commands,
+    query: xx.Query[xx.EntityId, xx.With[Stunned]],
+) -> None:
+    commands.despawn(query.result().value.numpy())
+
+
+def despawn_unstunned(
+    commands: xx.Commands,
+    query: xx.Query[xx.EntityId, xx.Without[Stunned]],
+) -> None:
+    commands.despawn(query.result().value.numpy())
+
+
+def spawn_entities(world: xx.World, commands: xx.Commands) -> None:
+    commands.spawn((Health,), 2)
+    (_, stunnedi) = commands.spawn((Health, Stunned), 2)
+    world.get_view(Stunned, stunnedi).time_left.fill([0, 1])
+
+
+@pytest.fixture
+def app() -> xx.SimulationApp:
+    app = xx.SimulationApp(num_entities=10)
+    app.add_pool(Health.create_pool(10))
+    app.add_pool(Stunned.create_pool(10))
+    app.add_resource(Removed([]))
+    app.add_startup_system(spawn_entities)
+    return app
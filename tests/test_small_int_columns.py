@@ -0,0 +1,94 @@
+import operator
+import typing
+
+import numpy as np
+import pytest
+import xecs as xx
+
+
+class Tile(xx.Component):
+    kind: xx.UInt8 = xx.uint8(default=3)
+    team: xx.Int8 = xx.int8(default=-1)
+    height: xx.Int16 = xx.int16(default=-300)
+    colour: xx.UInt16 = xx.uint16(default=60000)
+
+
+@pytest.mark.parametrize(
+    "op",
+    (
+        operator.add,
+        operator.sub,
+        operator.mul,
+        operator.floordiv,
+        operator.mod,
+        operator.pow,
+        operator.lt,
+        operator.ne,
+    ),
+)
+@pytest.mark.parametrize(
+    ("column", "dtype"),
+    (
+        (xx.Int8, np.int8),
+        (xx.Int16, np.int16),
+        (xx.UInt8, np.uint8),
+        (xx.UInt16, np.uint16),
+    ),
+)
+def test_operators_match_numpy(
+    column: typing.Any,
+    dtype: typing.Any,
+    op: typing.Any,
+) -> None:
+    xs = column.p_from_numpy(np.arange(6, 11, dtype=dtype))
+    ys = column.p_from_numpy(np.arange(1, 6, dtype=dtype) % 2 + 1)
+    assert np.array_equal(op(xs, ys), op(xs.numpy(), ys.numpy()))
+    assert np.array_equal(op(xs, 2), op(xs.numpy(), dtype(2)))
+
+
+@pytest.mark.parametrize(
+    ("column", "dtype"),
+    (
+        (xx.Int8, np.int8),
+        (xx.Int16, np.int16),
+        (xx.UInt8, np.uint8),
+        (xx.UInt16, np.uint16),
+    ),
+)
+def test_numpy_round_trip_keeps_dtype(
+    column: typing.Any,
+    dtype: typing.Any,
+) -> None:
+    array = np.arange(5, dtype=dtype)
+    xs = column.p_from_numpy(array)
+    assert xs.numpy().dtype == dtype
+    assert np.array_equal(xs.numpy(), array)
+    xs[xs.numpy() > 2] = 7
+    xs += column.p_from_value(1, 5)
+    assert np.array_equal(xs.numpy(), [1, 2, 3, 8, 8])
+
+
+def test_default_values_get_used() -> None:
+    app = xx.RealTimeApp(num_entities=10)
+    app.add_pool(Tile.create_pool(10))
+    app.add_startup_system(spawn_tiles)
+    app.add_system(check_default_values)
+    app.update()
+
+
+def spawn_tiles(commands: xx.Commands) -> None:
+    commands.spawn((Tile,), 10)
+
+
+def check_default_values(query: xx.Query[Tile]) -> None:
+    tile = query.result()
+    assert len(tile) == 10
+    assert np.all(tile.kind.numpy() == 3)
+    assert np.all(tile.team.numpy() == -1)
+    assert np.all(tile.height.numpy() == -300)
+    assert np.all(tile.colour.numpy() == 60000)
+
+
+def test_repr() -> None:
+    xs = xx.Int8.p_from_numpy(np.array([-1, 2], dtype=np.int8))
+    assert xs.to_str() == "<xecs.Int8 [-1, 2]>"
@@ -0,0 +1,105 @@
+import math
+import typing
+
+import numpy as np
+import pytest
+import xecs as xx
+
+
+class Point(xx.Component):
+    position: xx.Vec2
+    label: xx.Int32
+
+
+def test_pairs_within_matches_product_2(app: xx.RealTimeApp) -> None:
+    app.add_system(check_pairs_within_matches_product_2)
+    app.update()
+
+
+def check_pairs_within_matches_product_2(query: xx.Query[Point]) -> None:
+    point = query.result()
+    for radius in (0.0, 0.5, 1.5, 4.0, 100.0):
+        near1, near2 = query.pairs_within(point.position, radius)
+        assert near_pairs(near1, near2) == expected_pairs(query, radius)
+        near1, near2 = query.pairs_within(
+            (point.position.x, point.position.y), radius
+        )
+        assert near_pairs(near1, near2) == expected_pairs(query, radius)
+    for radius in (-1.0, math.inf, math.nan):
+        with pytest.raises(ValueError, match="radius"):
+            query.pairs_within(point.position, radius)
+
+
+def test_pairs_within_tuple_query(app: xx.RealTimeApp) -> None:
+    app.add_system(check_pairs_within_tuple_query)
+    app.update()
+
+
+def check_pairs_within_tuple_query(
+    query: xx.Query[tuple[Point, xx.Transform2]],
+) -> None:
+    point, transform = query.result()
+    transform.translation.x.fill(point.position.x)
+    transform.translation.y.fill(point.position.y)
+    (point1, transform1), (point2, _) = query.pairs_within(
+        transform.translation, 1.5
+    )
+    assert np.array_equal(
+        transform1.translation.x.numpy(), point1.position.x.numpy()
+    )
+    assert near_pairs(point1, point2) == expected_pairs(query, 1.5)
+
+
+def test_spatial_grid_errors() -> None:
+    xs = xx.Float32.p_from_numpy(np.arange(3, dtype=np.float32))
+    ys = xx.Float32.p_from_numpy(np.arange(2, dtype=np.float32))
+    with pytest.raises(ValueError, match="3 values .* got 2"):
+        xx.SpatialGrid(xs, ys, 1)
+    for cell_size in (0, -1, np.inf, np.nan):
+        with pytest.raises(ValueError, match="cell_size"):
+            xx.SpatialGrid(xs, xs, cell_size)
+    grid = xx.SpatialGrid(xs, xs, 1)
+    assert len(grid) == 3
+    for radius in (-1, np.inf, np.nan):
+        with pytest.raises(ValueError, match="radius"):
+            grid.pairs_within(radius, [])
+    indices = xx.ArrayViewIndices.with_capacity(2)
+    indices.spawn(2)
+    with pytest.raises(ValueError, match="3 values .* got 2"):
+        grid.pairs_within(1, [indices])
+
+
+def near_pairs(point1: Point, point2: Point) -> list[tuple[int, int]]:
+    return sorted(zip(point1.label.numpy(), point2.label.numpy()))
+
+
+def expected_pairs(
+    query: typing.Any,
+    radius: float,
+) -> list[tuple[int, int]]:
+    boid1, boid2 = query.product_2()
+    point1 = boid1 if isinstance(boid1, Point) else boid1[0]
+    point2 = boid2 if isinstance(boid2, Point) else boid2[0]
+    distance = np.hypot(
+        point1.position.x.numpy() - point2.position.x.numpy(),
+        point1.position.y.numpy() - point2.position.y.numpy(),
+    )
+    is_near = distance <= radius
+    return near_pairs(point1[is_near], point2[is_near])
+
+
+def spawn_entities(world: xx.World, commands: xx.Commands) -> None:
+    pointi, _ = commands.spawn((Point, xx.Transform2), 8)
+    point = world.get_view(Point, pointi)
+    point.label.fill(np.arange(8, dtype=np.int32))
+    point.position.x.fill([0, 1, 1, 2.5, 3, -4, -4, 10])
+    point.position.y.fill([0, 0, 1, 2.5, -3, -4, -3, 10])
+
+
+@pytest.fixture
+def app() -> xx.RealTimeApp:
+    app = xx.RealTimeApp(num_entities=10)
+    app.add_pool(Point.create_pool(10))
+    app.add_pool(xx.Transform2.create_pool(10))
+    app.add_startup_system(spawn_entities)
+    return app
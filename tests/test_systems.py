@@ -25,6 +25,14 @@ def test_query_with_two_components(app: xx.RealTimeApp) -> None:
     app.update()
 
 
+def test_queries_with_same_components(app: xx.RealTimeApp) -> None:
+    app.add_system(query_with_two_components)
+    app.add_system(query_with_reordered_components)
+    app.add_system(spawning_sytem)
+    app.add_system(query_with_reordered_components_after_spawn)
+    app.update()
+
+
 def test_system_with_resource(app: xx.RealTimeApp) -> None:
     app.add_system(system_with_resource)
     app.add_resource(Params("hi"))
@@ -56,6 +64,28 @@ def query_with_two_components(query: xx.Query[tuple[One, Two]]) -> None:
     assert isinstance(two, Two)
 
 
+def query_with_reordered_components(
+    query: xx.Query[tuple[Two, One]],
+) -> None:
+    two, one = query.result()
+    assert len(one) == len(two) == 5
+    assert isinstance(one, One)
+    assert isinstance(two, Two)
+
+
+def query_with_reordered_components_after_spawn(
+    query1: xx.Query[tuple[One, Two]],
+    query2: xx.Query[tuple[Two, One]],
+) -> None:
+    one1, two1 = query1.result()
+    two2, one2 = query2.result()
+    assert len(one1) == len(two1) == len(one2) == len(two2) == 7
+    assert sorted(one1.x.numpy()) == sorted(one2.x.numpy())
+    assert sorted(two1.y.numpy()) == sorted(two2.y.numpy())
+    assert 20 in one1.x.numpy()
+    assert 40 in two2.y.numpy()
+
+
 def system_with_resource(params: Params, query: xx.Query[tuple[One]]) -> None:
     (one,) = query.result()
     assert isinstance(one, One)
@@ -0,0 +1,260 @@
+import operator
+import typing
+
+import numpy as np
+import pytest
+import xecs as xx
+
+PAIRS = (
+    ((xx.Float32, np.float32), (xx.Float64, np.float64)),
+    ((xx.Float32, np.float32), (xx.Int32, np.int32)),
+    ((xx.Float32, np.float32), (xx.UInt32, np.uint32)),
+    ((xx.Float64, np.float64), (xx.Float32, np.float32)),
+    ((xx.Float64, np.float64), (xx.Int32, np.int32)),
+    ((xx.Float64, np.float64), (xx.UInt32, np.uint32)),
+    ((xx.Int32, np.int32), (xx.Float32, np.float32)),
+    ((xx.Int32, np.int32), (xx.Float64, np.float64)),
+    ((xx.Int32, np.int32), (xx.UInt32, np.uint32)),
+    ((xx.UInt32, np.uint32), (xx.Float32, np.float32)),
+    ((xx.UInt32, np.uint32), (xx.Float64, np.float64)),
+    ((xx.UInt32, np.uint32), (xx.Int32, np.int32)),
+)
+
+UNSUPPORTED_PAIRS = (
+    ((xx.Int8, np.int8), (xx.Int16, np.int16)),
+    ((xx.Int16, np.int16), (xx.Int32, np.int32)),
+    ((xx.Int64, np.int64), (xx.Float32, np.float32)),
+    ((xx.UInt8, np.uint8), (xx.Int8, np.int8)),
+    ((xx.UInt16, np.uint16), (xx.Float64, np.float64)),
+    ((xx.UInt64, np.uint64), (xx.UInt32, np.uint32)),
+    ((xx.Int8, np.int8), (xx.Float32, np.float32)),
+    ((xx.Float32, np.float32), (xx.Int64, np.int64)),
+    ((xx.Float64, np.float64), (xx.UInt8, np.uint8)),
+)
+
+
+@pytest.mark.parametrize(
+    "op",
+    (
+        operator.add,
+        operator.sub,
+        operator.mul,
+        operator.floordiv,
+        operator.mod,
+        operator.lt,
+        operator.ge,
+        operator.eq,
+    ),
+)
+@pytest.mark.parametrize(("lhs", "rhs"), PAIRS)
+def test_operator_promotes_like_numpy(
+    lhs: tuple[typing.Any, typing.Any],
+    rhs: tuple[typing.Any, typing.Any],
+    op: typing.Any,
+) -> None:
+    (lhs_column, lhs_dtype), (rhs_column, rhs_dtype) = lhs, rhs
+    lhs_values = np.arange(6, 11, dtype=lhs_dtype)
+    rhs_values = np.array([1, 2, 1, 2, 1], dtype=rhs_dtype)
+    expected = op(lhs_values, rhs_values)
+    result = op(
+        lhs_column.p_from_numpy(lhs_values),
+        rhs_column.p_from_numpy(rhs_values),
+    )
+    assert result.dtype == expected.dtype
+    assert np.array_equal(result, expected)
+
+
+@pytest.mark.parametrize(
+    ("lhs", "rhs"),
+    (
+        ((xx.Float32, np.float32), (xx.Float64, np.float64)),
+        ((xx.Float32, np.float32), (xx.Int32, np.int32)),
+        ((xx.Float32, np.float32), (xx.UInt32, np.uint32)),
+        ((xx.Float64, np.float64), (xx.Int32, np.int32)),
+        ((xx.Int32, np.int32), (xx.UInt32, np.uint32)),
+    ),
+)
+def test_in_place_operator_casts_back(
+    lhs: tuple[typing.Any, typing.Any],
+    rhs: tuple[typing.Any, typing.Any],
+) -> None:
+    (lhs_column, lhs_dtype), (rhs_column, rhs_dtype) = lhs, rhs
+    lhs_values = np.arange(6, 11, dtype=lhs_dtype)
+    rhs_values = np.array([1, 2, 1, 2, 1], dtype=rhs_dtype)
+    xs = lhs_column.p_from_numpy(lhs_values)
+    xs *= rhs_column.p_from_numpy(rhs_values)
+    lhs_values *= rhs_values
+    assert xs.numpy().dtype == lhs_dtype
+    assert np.array_equal(xs.numpy(), lhs_values)
+
+
+@pytest.mark.parametrize(
+    ("lhs", "rhs"),
+    (
+        ((xx.Int32, np.int32), (xx.Float32, np.float32)),
+        ((xx.Int32, np.int32), (xx.Float64, np.float64)),
+        ((xx.UInt32, np.uint32), (xx.Float32, np.float32)),
+        ((xx.UInt32, np.uint32), (xx.Float64, np.float64)),
+        ((xx.UInt32, np.uint32), (xx.Int32, np.int32)),
+    ),
+)
+def test_in_place_operator_rejects_lesser_kind(
+    lhs: tuple[typing.Any, typing.Any],
+    rhs: tuple[typing.Any, typing.Any],
+) -> None:
+    (lhs_column, lhs_dtype), (rhs_column, rhs_dtype) = lhs, rhs
+    xs = lhs_column.p_from_numpy(np.arange(5, dtype=lhs_dtype))
+    with pytest.raises(TypeError, match="cannot cast"):
+        xs += rhs_column.p_from_numpy(np.ones(5, dtype=rhs_dtype))
+    assert np.array_equal(xs.numpy(), np.arange(5))
+
+
+@pytest.mark.parametrize(("lhs", "rhs"), UNSUPPORTED_PAIRS)
+def test_unsupported_column_types_are_rejected_by_name(
+    lhs: tuple[typing.Any, typing.Any],
+    rhs: tuple[typing.Any, typing.Any],
+) -> None:
+    (lhs_column, lhs_dtype), (rhs_column, rhs_dtype) = lhs, rhs
+    xs = lhs_column.p_from_numpy(np.arange(3, dtype=lhs_dtype))
+    ys = rhs_column.p_from_numpy(np.ones(3, dtype=rhs_dtype))
+    message = (
+        f"cannot combine {np.dtype(lhs_dtype)} and {np.dtype(rhs_dtype)}"
+    )
+    with pytest.raises(TypeError, match=message):
+        xs + ys
+    with pytest.raises(TypeError, match=message):
+        xs < ys
+    with pytest.raises(TypeError, match=message):
+        xs**ys
+    with pytest.raises(TypeError, match=message):
+        xs *= ys
+    with pytest.raises(TypeError, match=message):
+        xs **= ys
+    assert np.array_equal(xs.numpy(), [0, 1, 2])
+
+
+@pytest.mark.parametrize(
+    ("column", "dtype", "value"),
+    (
+        (xx.Int8, np.int8, 300),
+        (xx.Int8, np.int8, -129),
+        (xx.Int16, np.int16, 2**15),
+        (xx.Int32, np.int32, 2**31),
+        (xx.Int64, np.int64, 2**63),
+        (xx.UInt8, np.uint8, -1),
+        (xx.UInt16, np.uint16, 2**16),
+        (xx.UInt32, np.uint32, -1),
+        (xx.UInt64, np.uint64, 2**64),
+    ),
+)
+def test_out_of_range_scalar_raises_overflow_error(
+    column: typing.Any,
+    dtype: typing.Any,
+    value: int,
+) -> None:
+    xs = column.p_from_numpy(np.arange(3, dtype=dtype))
+    message = f"{value} is out of range for {np.dtype(dtype)}"
+    with pytest.raises(OverflowError, match=message):
+        xs + value
+    with pytest.raises(OverflowError, match=message):
+        xs == value
+    with pytest.raises(OverflowError, match=message):
+        xs -= value
+    with pytest.raises(OverflowError, match=message):
+        xs.fill(value)
+    assert np.array_equal(xs.numpy(), [0, 1, 2])
+
+
+def test_integer_power_rejects_bad_exponents() -> None:
+    xs = xx.Int8.p_from_numpy(np.arange(3, dtype=np.int8))
+    with pytest.raises(ValueError, match="exponents must be between"):
+        xs**-1
+    with pytest.raises(ValueError, match="exponents must be between"):
+        xs **= 2**32
+    message = "int8 or uint32 exponents, got float32"
+    with pytest.raises(TypeError, match=message):
+        xs ** np.ones(3, dtype=np.float32)
+    assert np.array_equal(xs.numpy(), [0, 1, 2])
+
+
+def test_float_power_of_int32_array() -> None:
+    xs = xx.Float32.p_from_numpy(np.array([2, 3, 4], dtype=np.float32))
+    exponents = np.array([3, -1, 0], dtype=np.int32)
+    assert np.allclose(xs**exponents, [8, 1 / 3, 1])
+    with pytest.raises(TypeError, match="float32 or int32 exponents"):
+        xs ** np.ones(3, dtype=np.int64)
+
+
+def test_grid_cell_offsets_position() -> None:
+    position = xx.Float32.p_from_numpy(np.array([0.5, 1.5], dtype=np.float32))
+    cell = xx.Int32.p_from_numpy(np.array([-2, 3], dtype=np.int32))
+    position += cell
+    assert np.array_equal(position.numpy(), [-1.5, 4.5])
+
+
+def test_unsigned_compares_with_signed_exactly() -> None:
+    xs = xx.UInt32.p_from_numpy(np.array([0, 2**31 + 1], dtype=np.uint32))
+    ys = xx.Int32.p_from_numpy(np.array([-1, 5], dtype=np.int32))
+    assert np.array_equal(xs > ys, [True, True])
+    assert np.array_equal(ys < xs, [True, True])
+
+
+@pytest.mark.parametrize(
+    ("column", "dtype"),
+    (
+        (xx.Float32, np.float32),
+        (xx.Int8, np.int8),
+        (xx.Int32, np.int32),
+        (xx.UInt32, np.uint32),
+        (xx.UInt64, np.uint64),
+    ),
+)
+def test_bool_keeps_column_type(column: typing.Any, dtype: typing.Any) -> None:
+    xs = column.p_from_numpy(np.array([3, 4, 5], dtype=dtype))
+    mask = xx.Bool.p_from_numpy(np.array([True, False, True]))
+    result = xs * mask
+    assert result.dtype == dtype
+    assert np.array_equal(result, [3, 0, 5])
+    xs += mask
+    assert np.array_equal(xs.numpy(), [4, 4, 6])
+
+
+def test_float_power_of_int32_column() -> None:
+    xs = xx.Float32.p_from_numpy(np.array([2, 3, 4], dtype=np.float32))
+    exponents = xx.Int32.p_from_numpy(np.array([3, -1, 0], dtype=np.int32))
+    assert np.allclose(xs**exponents, [8, 1 / 3, 1])
+    xs **= exponents
+    assert np.allclose(xs.numpy(), [8, 1 / 3, 1])
+
+
+@pytest.mark.parametrize(
+    ("column", "dtype", "array_dtype"),
+    (
+        (xx.Int32, np.int32, np.float64),
+        (xx.Float32, np.float32, np.float64),
+        (xx.Int32, np.int32, np.int64),
+    ),
+)
+def test_mismatched_array_dtype_gives_clear_error(
+    column: typing.Any,
+    dtype: typing.Any,
+    array_dtype: typing.Any,
+) -> None:
+    xs = column.p_from_numpy(np.arange(3, dtype=dtype))
+    values = np.ones(3, dtype=array_dtype)
+    message = f"{np.dtype(dtype)} values, got {np.dtype(array_dtype)}"
+    with pytest.raises(TypeError, match=message):
+        xs + values
+    with pytest.raises(TypeError, match=message):
+        xs += values
+    with pytest.raises(TypeError, match=message):
+        xs.fill(values)
+    with pytest.raises(TypeError, match=message):
+        xs[:] = values
+    assert np.array_equal(xs.numpy(), [0, 1, 2])
+
+
+def test_column_of_other_type_is_rejected_by_fill() -> None:
+    xs = xx.Int32.p_from_numpy(np.arange(3, dtype=np.int32))
+    with pytest.raises(TypeError, match="column of another type"):
+        xs.fill(xx.Float32.p_from_value(1, 3))
@@ -0,0 +1,95 @@
+import operator
+import typing
+
+import numpy as np
+import pytest
+import xecs as xx
+
+
+class Account(xx.Component):
+    balance: xx.Float64 = xx.float64(default=0.1)
+    ticks: xx.Int64 = xx.int64(default=-(2**40))
+    count: xx.UInt64 = xx.uint64(default=2**40)
+
+
+def test_float64_keeps_precision() -> None:
+    xs = xx.Float64.p_from_numpy(np.array([1e15, 1], dtype=np.float64))
+    xs += 0.25
+    assert np.array_equal(xs.numpy(), [1e15 + 0.25, 1.25])
+    assert xs.numpy().dtype == np.float64
+
+
+def test_int64_holds_values_beyond_int32() -> None:
+    xs = xx.Int64.p_from_value(2**40, 3)
+    xs *= 4
+    assert np.all(xs.numpy() == 2**42)
+    assert xs.numpy().dtype == np.int64
+
+
+def test_uint64_holds_values_beyond_int64() -> None:
+    xs = xx.UInt64.p_from_value(2**63, 3)
+    xs += 1
+    assert np.all(xs.numpy() == 2**63 + 1)
+    assert xs.numpy().dtype == np.uint64
+
+
+@pytest.mark.parametrize(
+    "op",
+    (
+        operator.add,
+        operator.sub,
+        operator.mul,
+        operator.floordiv,
+        operator.mod,
+        operator.lt,
+        operator.eq,
+    ),
+)
+@pytest.mark.parametrize(
+    ("column", "dtype"),
+    (
+        (xx.Float64, np.float64),
+        (xx.Int64, np.int64),
+        (xx.UInt64, np.uint64),
+    ),
+)
+def test_operators_match_numpy(
+    column: typing.Any,
+    dtype: typing.Any,
+    op: typing.Any,
+) -> None:
+    xs = column.p_from_numpy(np.arange(6, 11, dtype=dtype))
+    ys = column.p_from_numpy(np.arange(1, 6, dtype=dtype))
+    assert np.array_equal(op(xs, ys), op(xs.numpy(), ys.numpy()))
+    assert np.array_equal(op(xs, 3), op(xs.numpy(), dtype(3)))
+
+
+def test_masked_assignment() -> None:
+    xs = xx.Int64.p_from_numpy(np.zeros(5, dtype=np.int64))
+    xs[xs.numpy() == 0] = 2**40
+    assert np.all(xs.numpy() == 2**40)
+
+
+def test_default_values_get_used() -> None:
+    app = xx.RealTimeApp(num_entities=10)
+    app.add_pool(Account.create_pool(10))
+    app.add_startup_system(spawn_accounts)
+    app.add_system(check_default_values)
+    app.update()
+
+
+def spawn_accounts(commands: xx.Commands) -> None:
+    commands.spawn((Account,), 10)
+
+
+def check_default_values(query: xx.Query[Account]) -> None:
+    account = query.result()
+    assert len(account) == 10
+    assert np.all(account.balance.numpy() == 0.1)
+    assert np.all(account.ticks.numpy() == -(2**40))
+    assert np.all(account.count.numpy() == 2**40)
+
+
+def test_repr() -> None:
+    xs = xx.UInt64.p_from_numpy(np.array([1, 2], dtype=np.uint64))
+    assert xs.to_str() == "<xecs.UInt64 [1, 2]>"